@@ -0,0 +1,164 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt::{self, Display, Formatter},
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+static ALLOCATIONS: AtomicUsize = AtomicUsize::new(0);
+static BYTES: AtomicUsize = AtomicUsize::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// A global allocator that counts allocations on top of [`System`], so that
+/// the runner can report them for each stage. Opt in from a binary with:
+///
+/// ```ignore
+/// #[global_allocator]
+/// static ALLOCATOR: aoc::CountingAllocator = aoc::CountingAllocator;
+/// ```
+pub struct CountingAllocator;
+
+fn record(size: usize) {
+    ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+    BYTES.fetch_add(size, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record(layout.size());
+        }
+        return ptr;
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            LIVE.fetch_sub(layout.size(), Ordering::Relaxed);
+            record(new_size);
+        }
+        return new_ptr;
+    }
+}
+
+/// Allocations made during a single stage.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    pub count: usize,
+    pub bytes: usize,
+    /// Most bytes live at once, above what was live when the stage started.
+    pub peak: usize,
+}
+
+impl Display for AllocStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} allocated, {} peak",
+            self.count,
+            Bytes(self.bytes),
+            Bytes(self.peak)
+        )
+    }
+}
+
+struct Bytes(usize);
+
+impl Display for Bytes {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let units = ["KiB", "MiB", "GiB"];
+
+        match self.0 < 1024 {
+            true => write!(f, "{} B", self.0),
+            false => {
+                let (value, unit) =
+                    units[1..]
+                        .iter()
+                        .fold((self.0 as f64 / 1024.0, units[0]), |(v, u), next| {
+                            match v >= 1024.0 {
+                                true => (v / 1024.0, next),
+                                false => (v, u),
+                            }
+                        });
+
+                write!(f, "{:.2} {}", value, unit)
+            }
+        }
+    }
+}
+
+/// Counts the allocations between [`Counter::start`] and [`Counter::finish`].
+pub(crate) struct Counter {
+    allocations: usize,
+    bytes: usize,
+    live: usize,
+}
+
+impl Counter {
+    /// `None` unless [`CountingAllocator`] is the global allocator.
+    pub(crate) fn start() -> Option<Self> {
+        if ALLOCATIONS.load(Ordering::Relaxed) == 0 {
+            return None;
+        }
+
+        let live = LIVE.load(Ordering::Relaxed);
+        PEAK.store(live, Ordering::Relaxed);
+
+        return Some(Self {
+            allocations: ALLOCATIONS.load(Ordering::Relaxed),
+            bytes: BYTES.load(Ordering::Relaxed),
+            live,
+        });
+    }
+
+    pub(crate) fn finish(self) -> AllocStats {
+        return AllocStats {
+            count: ALLOCATIONS.load(Ordering::Relaxed) - self.allocations,
+            bytes: BYTES.load(Ordering::Relaxed) - self.bytes,
+            peak: PEAK.load(Ordering::Relaxed).saturating_sub(self.live),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{AllocStats, Bytes, Counter, CountingAllocator};
+
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn test_count_allocations() {
+        let counter = Counter::start().unwrap();
+        let v: Vec<u8> = Vec::with_capacity(4096);
+        drop(v);
+        let stats = counter.finish();
+
+        // Other tests allocate concurrently, so these are only lower bounds.
+        assert!(stats.count >= 1);
+        assert!(stats.bytes >= 4096);
+    }
+
+    #[test]
+    fn test_display_alloc_stats() {
+        let stats = AllocStats {
+            count: 3,
+            bytes: 1536,
+            peak: 512,
+        };
+
+        assert_eq!(
+            stats.to_string(),
+            "3 allocations, 1.50 KiB allocated, 512 B peak"
+        );
+        assert_eq!(Bytes(3 * 1024 * 1024).to_string(), "3.00 MiB");
+    }
+}
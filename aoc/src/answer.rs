@@ -0,0 +1,86 @@
+use std::fmt::{self, Display, Formatter};
+
+/// A puzzle answer. Integers are stored as `i128` so that `u64`, `i64` and
+/// products overflowing either of them all compare equal by value. The few
+/// `u128` values past `i128::MAX` are kept as their decimal text instead, so
+/// convert with `Answer::from` rather than `as i128`, which would wrap them.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+}
+
+impl Display for Answer {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Integer(v) => write!(f, "{}", v),
+            Answer::Text(v) => write!(f, "{}", v),
+        }
+    }
+}
+
+macro_rules! impl_from_integer {
+    ($($t:ty),*) => {
+        $(
+            impl From<$t> for Answer {
+                fn from(value: $t) -> Self {
+                    Answer::Integer(value as i128)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_integer!(u8, u16, u32, u64, usize, i8, i16, i32, i64, isize, i128);
+
+impl From<u128> for Answer {
+    fn from(value: u128) -> Self {
+        match i128::try_from(value) {
+            Ok(value) => Answer::Integer(value),
+            Err(_) => Answer::Text(value.to_string()),
+        }
+    }
+}
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_owned())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Answer;
+
+    #[test]
+    fn test_integer_answers_compare_by_value() {
+        assert_eq!(Answer::from(42_u64), Answer::from(42_i32));
+        assert_eq!(Answer::from(-3_i64), Answer::Integer(-3));
+        assert_ne!(Answer::from(42_u64), Answer::from("42"));
+        assert_eq!(Answer::from(42_u128), Answer::from(42_u8));
+    }
+
+    #[test]
+    fn test_u128_past_i128() {
+        let answer = Answer::from(u128::MAX);
+
+        assert_eq!(answer.to_string(), u128::MAX.to_string());
+        assert_ne!(answer, Answer::from(u128::MAX as i128));
+    }
+
+    #[test]
+    fn test_display_answer() {
+        assert_eq!(Answer::from(-1140_i64).to_string(), "-1140");
+        assert_eq!(
+            Answer::from(u64::MAX as i128 * 2).to_string(),
+            "36893488147419103230"
+        );
+        assert_eq!(Answer::from("EFGJH").to_string(), "EFGJH");
+    }
+}
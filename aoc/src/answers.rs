@@ -0,0 +1,220 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use toml::{Table, Value};
+
+use crate::{Answer, Day};
+
+pub fn default_answers_path(day: Day) -> PathBuf {
+    day.dir().join("answers.toml")
+}
+
+pub fn example_answers_path(day: Day) -> PathBuf {
+    day.dir().join("examples").join("answers.toml")
+}
+
+/// A known answers file that could not be read or written, e.g. one that is
+/// not valid TOML.
+#[derive(Debug)]
+pub enum AnswersError {
+    Read(PathBuf, io::Error),
+    Write(PathBuf, io::Error),
+}
+
+impl Display for AnswersError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            AnswersError::Read(path, error) => {
+                write!(
+                    f,
+                    "Could not read known answers {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+            AnswersError::Write(path, error) => {
+                write!(
+                    f,
+                    "Could not write known answers {}: {}",
+                    path.display(),
+                    error
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for AnswersError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            AnswersError::Read(_, error) | AnswersError::Write(_, error) => Some(error),
+        }
+    }
+}
+
+/// Outcome of comparing a computed answer against the known answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Pass,
+    Fail,
+    /// No known answer has been recorded for this part yet.
+    New,
+    /// The day ran out of time before answering this part.
+    Timeout,
+}
+
+impl Display for Status {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Status::Pass => "PASS",
+            Status::Fail => "FAIL",
+            Status::New => "NEW",
+            Status::Timeout => "TIMEOUT",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+/// Known answers for a single day, stored as one TOML table per input:
+///
+/// ```toml
+/// [input]
+/// part1 = 27454
+/// part2 = 6857330
+/// ```
+#[derive(Debug, Default)]
+pub struct KnownAnswers {
+    table: Table,
+}
+
+fn part_key(part: u8) -> String {
+    format!("part{}", part)
+}
+
+impl KnownAnswers {
+    /// Loads the answers file, treating a missing file as having no answers.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => contents
+                .parse()
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        fs::write(path, self.to_string())
+    }
+
+    pub fn get(&self, input: &str, part: u8) -> Option<String> {
+        let value = self.table.get(input)?.get(part_key(part))?;
+
+        match value {
+            Value::String(v) => Some(v.clone()),
+            Value::Integer(v) => Some(v.to_string()),
+            _ => None,
+        }
+    }
+
+    pub fn check(&self, input: &str, part: u8, actual: Option<&Answer>) -> Option<Status> {
+        let expected = self.get(input, part);
+
+        match (expected, actual) {
+            (None, None) => None,
+            (None, Some(_)) => Some(Status::New),
+            (Some(expected), Some(actual)) if expected == actual.to_string() => Some(Status::Pass),
+            (Some(_), _) => Some(Status::Fail),
+        }
+    }
+
+    pub fn record(&mut self, input: &str, part: u8, answer: &Answer) {
+        let value = match answer {
+            Answer::Integer(v) => match i64::try_from(*v) {
+                Ok(v) => Value::Integer(v),
+                Err(_) => Value::String(v.to_string()),
+            },
+            Answer::Text(v) => Value::String(v.clone()),
+        };
+
+        let section = self
+            .table
+            .entry(input)
+            .or_insert_with(|| Value::Table(Table::new()));
+
+        if let Value::Table(section) = section {
+            section.insert(part_key(part), value);
+        }
+    }
+}
+
+impl std::str::FromStr for KnownAnswers {
+    type Err = toml::de::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Ok(Self { table: s.parse()? })
+    }
+}
+
+impl Display for KnownAnswers {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{KnownAnswers, Status};
+    use crate::Answer;
+
+    const ANSWERS: &str = "\
+        [input]\n\
+        part1 = 114\n\
+        part2 = \"-2\"\n\
+    ";
+
+    #[test]
+    fn test_check_answers() {
+        let answers: KnownAnswers = ANSWERS.parse().unwrap();
+
+        let pass = answers.check("input", 1, Some(&Answer::from(114)));
+        assert_eq!(pass, Some(Status::Pass));
+
+        let signed = answers.check("input", 2, Some(&Answer::from(-2)));
+        assert_eq!(signed, Some(Status::Pass));
+
+        let fail = answers.check("input", 1, Some(&Answer::from(115)));
+        assert_eq!(fail, Some(Status::Fail));
+
+        let missing = answers.check("input", 1, None);
+        assert_eq!(missing, Some(Status::Fail));
+
+        let new = answers.check("other", 1, Some(&Answer::from(1)));
+        assert_eq!(new, Some(Status::New));
+
+        assert_eq!(answers.check("other", 2, None), None);
+    }
+
+    #[test]
+    fn test_record_answers() {
+        let mut answers = KnownAnswers::default();
+
+        answers.record("input", 1, &Answer::from(42));
+        answers.record("input", 2, &Answer::Integer(i64::MAX as i128 + 1));
+        answers.record("alt", 1, &Answer::from("ABC"));
+
+        let reloaded: KnownAnswers = answers.to_string().parse().unwrap();
+
+        assert_eq!(reloaded.get("input", 1), Some("42".to_string()));
+        assert_eq!(
+            reloaded.get("input", 2),
+            Some("9223372036854775808".to_string())
+        );
+        assert_eq!(reloaded.get("alt", 1), Some("ABC".to_string()));
+        assert_eq!(reloaded.get("alt", 2), None);
+    }
+}
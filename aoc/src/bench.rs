@@ -0,0 +1,190 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    time::Duration,
+};
+
+use crate::{Solver, Stage};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Stats {
+    pub min: Duration,
+    pub median: Duration,
+    pub mean: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+
+        let mut sorted = samples.to_vec();
+        sorted.sort();
+
+        let mid = sorted.len() / 2;
+        let median = match sorted.len() % 2 {
+            0 => (sorted[mid - 1] + sorted[mid]) / 2,
+            _ => sorted[mid],
+        };
+
+        let secs: Vec<f64> = samples.iter().map(Duration::as_secs_f64).collect();
+        let mean = secs.iter().sum::<f64>() / secs.len() as f64;
+        let variance = secs.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / secs.len() as f64;
+
+        return Some(Self {
+            min: sorted[0],
+            median,
+            mean: Duration::from_secs_f64(mean),
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        });
+    }
+
+    /// Input bytes processed per second, based on the mean time.
+    pub fn throughput(&self, bytes: usize) -> f64 {
+        bytes as f64 / self.mean.as_secs_f64().max(f64::MIN_POSITIVE)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Benchmark {
+    pub iterations: usize,
+    pub warmup: usize,
+    pub input_bytes: usize,
+    pub stages: Vec<(Stage, Stats)>,
+    pub total: Stats,
+}
+
+/// Number of untimed runs made before measuring, to warm caches.
+pub fn warmup_runs(iterations: usize) -> usize {
+    (iterations / 10).clamp(1, 10)
+}
+
+pub fn bench(solver: &dyn Solver, input: &str, iterations: usize) -> Benchmark {
+    let warmup = warmup_runs(iterations);
+
+    for _ in 0..warmup {
+        solver.solve(input);
+    }
+
+    let mut stages: Vec<(Stage, Vec<Duration>)> = vec![];
+    let mut totals = vec![];
+
+    for _ in 0..iterations {
+        let report = solver.solve(input);
+
+        for (stage, time) in &report.timings {
+            match stages.iter_mut().find(|(s, _)| s == stage) {
+                Some((_, samples)) => samples.push(*time),
+                None => stages.push((*stage, vec![*time])),
+            }
+        }
+
+        totals.push(report.total_time());
+    }
+
+    let stages = stages
+        .iter()
+        .filter_map(|(stage, samples)| Stats::from_samples(samples).map(|s| (*stage, s)))
+        .collect();
+
+    return Benchmark {
+        iterations,
+        warmup,
+        input_bytes: input.len(),
+        stages,
+        total: Stats::from_samples(&totals).expect("Benchmark needs at least one iteration"),
+    };
+}
+
+struct Throughput(f64);
+
+impl Display for Throughput {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let units = ["B/s", "KiB/s", "MiB/s", "GiB/s"];
+
+        let (value, unit) =
+            units[1..]
+                .iter()
+                .fold((self.0, units[0]), |(v, u), next| match v >= 1024.0 {
+                    true => (v / 1024.0, next),
+                    false => (v, u),
+                });
+
+        write!(f, "{:.2} {}", value, unit)
+    }
+}
+
+impl Display for Benchmark {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        writeln!(
+            f,
+            "Benchmark: {} runs after {} warm-up, {} bytes of input",
+            self.iterations, self.warmup, self.input_bytes
+        )?;
+
+        let total = ("Total".to_string(), self.total);
+        let rows = self
+            .stages
+            .iter()
+            .map(|(stage, stats)| (stage.to_string(), *stats))
+            .chain([total]);
+
+        for (name, stats) in rows {
+            writeln!(
+                f,
+                "  {:<7} min {:>12?}  median {:>12?}  mean {:>12?}  stddev {:>12?}  {}",
+                name,
+                stats.min,
+                stats.median,
+                stats.mean,
+                stats.stddev,
+                Throughput(stats.throughput(self.input_bytes))
+            )?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{bench, warmup_runs, Stats, Throughput};
+    use crate::Stage;
+
+    #[test]
+    fn test_stats_from_samples() {
+        let samples = [4, 1, 3, 2].map(Duration::from_millis);
+        let stats = Stats::from_samples(&samples).unwrap();
+
+        assert_eq!(stats.min, Duration::from_millis(1));
+        assert_eq!(stats.median, Duration::from_micros(2500));
+        assert_eq!(stats.mean.as_micros(), 2500);
+        assert_eq!(stats.stddev.as_micros(), 1118);
+
+        assert!(Stats::from_samples(&[]).is_none());
+    }
+
+    #[test]
+    fn test_throughput() {
+        let stats = Stats::from_samples(&[Duration::from_millis(500)]).unwrap();
+
+        assert_eq!(stats.throughput(1024), 2048.0);
+        assert_eq!(Throughput(2048.0).to_string(), "2.00 KiB/s");
+        assert_eq!(Throughput(512.0).to_string(), "512.00 B/s");
+    }
+
+    #[test]
+    fn test_bench_stages() {
+        let solver: fn(&str) -> crate::Result = |input| (Some(input.len().into()), None);
+        let result = bench(&solver, "abcd", 20);
+
+        assert_eq!(result.iterations, 20);
+        assert_eq!(result.warmup, warmup_runs(20));
+        assert_eq!(result.input_bytes, 4);
+        assert_eq!(result.stages.len(), 1);
+        assert_eq!(result.stages[0].0, Stage::Solve);
+    }
+}
@@ -0,0 +1,65 @@
+use std::{cell::Cell, time::Instant};
+
+thread_local! {
+    static DEADLINE: Cell<Option<Instant>> = const { Cell::new(None) };
+}
+
+/// Lets a long-running solver notice that the runner's time budget has run
+/// out, so it can give up and return `None`:
+///
+/// ```
+/// let cancel = aoc::CancelToken::current();
+/// loop {
+///     if cancel.is_cancelled() {
+///         break;
+///     }
+///     # break;
+/// }
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CancelToken {
+    deadline: Option<Instant>,
+}
+
+impl CancelToken {
+    /// The token for the day being solved on this thread. It never cancels
+    /// outside of a run with `--timeout`, e.g. in unit tests.
+    pub fn current() -> Self {
+        return Self {
+            deadline: DEADLINE.with(Cell::get),
+        };
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.deadline
+            .is_some_and(|deadline| Instant::now() >= deadline)
+    }
+}
+
+/// Runs `f` with [`CancelToken::current`] cancelling at `deadline`.
+pub(crate) fn with_deadline<T>(deadline: Option<Instant>, f: impl FnOnce() -> T) -> T {
+    let previous = DEADLINE.with(|d| d.replace(deadline));
+    let result = f();
+    DEADLINE.with(|d| d.set(previous));
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{with_deadline, CancelToken};
+
+    #[test]
+    fn test_cancel_token() {
+        assert!(!CancelToken::current().is_cancelled());
+
+        let past = Instant::now() - Duration::from_millis(1);
+        assert!(with_deadline(Some(past), || CancelToken::current().is_cancelled()));
+
+        let future = Instant::now() + Duration::from_secs(60);
+        assert!(!with_deadline(Some(future), || CancelToken::current().is_cancelled()));
+
+        assert!(!CancelToken::current().is_cancelled());
+    }
+}
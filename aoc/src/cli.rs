@@ -0,0 +1,715 @@
+use std::{
+    env,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::ExitCode,
+    str::FromStr,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use clap::{Args, Parser, Subcommand};
+
+use crate::{
+    answers::default_answers_path,
+    dashboard,
+    day::{parse_day_number, parse_year},
+    fetch::{self, Fetcher},
+    gen::Rng,
+    history::{self, History},
+    input::{self, Input, Source},
+    log::{self, Filter, Level},
+    output::{Format, Printer},
+    runner, scaffold,
+    submit::{Outcome, Submitter},
+    watch, Answer, Day, KnownAnswers, Register, Registry, Solver, Status, ROOT_YEAR,
+};
+
+#[derive(Debug, Parser)]
+#[command(name = "aoc", about = "Advent of Code solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// Log more from the solvers: `-v` for info, `-vv` debug, `-vvv` trace
+    #[arg(short, long, action = clap::ArgAction::Count, global = true)]
+    verbose: u8,
+    /// Log filter such as `debug` or `info,17=trace,2022/3=off`, overriding `-v`
+    #[arg(long, value_name = "FILTER", global = true)]
+    log: Option<Filter>,
+    /// Year of days given without one, like `5` rather than `2023/5`.
+    /// Defaults to the latest registered year
+    #[arg(long, env = "AOC_YEAR", global = true)]
+    year: Option<u16>,
+    /// Directory that fetched inputs and submissions are cached in, and that
+    /// inputs missing from `./dayN/src` are read from. Defaults to
+    /// `~/.cache/aoc`
+    #[arg(long, env = "AOC_CACHE_DIR", global = true)]
+    cache_dir: Option<PathBuf>,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Run a single day, or every registered day of a year or of all years,
+    /// and summarise them in one table
+    Run {
+        /// Day such as `5` or `2023/5`, a year such as `2023`, or `all`
+        day: Selection,
+        /// Input files or directories of `*.txt` files, or `-` for stdin.
+        /// Defaults to `./dayN/src/input.txt`
+        inputs: Vec<PathBuf>,
+        /// Puzzle input given directly as a string
+        #[arg(long, value_name = "TEXT")]
+        inline: Vec<String>,
+        /// Run the puzzle examples from `./dayN/examples`, or just the named one
+        #[arg(long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
+        example: Option<String>,
+        #[command(flatten)]
+        options: RunOptions,
+    },
+    /// Download a day's puzzle input into the cache, unless it is already there
+    Fetch {
+        day: DayArg,
+        #[command(flatten)]
+        server: ServerOptions,
+    },
+    /// Solve a part against the default input and submit the answer
+    Submit {
+        day: DayArg,
+        #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: u8,
+        /// Submit this answer instead of solving the part
+        #[arg(long)]
+        answer: Option<String>,
+        /// Give up on solving the part after this many seconds
+        #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+        #[command(flatten)]
+        server: ServerOptions,
+    },
+    /// Create a new `./dayN` crate, or `./YYYY/dayN` for years other than
+    /// 2023, from the template and register it
+    New { day: DayArg },
+    /// Print a random input in the day's format, e.g. to stress-test a solver
+    Gen {
+        day: DayArg,
+        /// Seed for the generator, the same seed always giving the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        /// Roughly how many lines, grid rows or items to generate
+        #[arg(long, default_value_t = 100, value_parser = clap::value_parser!(u64).range(1..))]
+        size: u64,
+    },
+    /// Rebuild and rerun a day whenever its crate or input changes, showing
+    /// how the answers and timings moved
+    Watch {
+        day: DayArg,
+        /// Input files or directories of `*.txt` files. Defaults to
+        /// `./dayN/src/input.txt`
+        inputs: Vec<PathBuf>,
+        /// Run the puzzle examples from `./dayN/examples`, or just the named one
+        #[arg(long, value_name = "NAME", num_args = 0..=1, default_missing_value = "")]
+        example: Option<String>,
+        /// Build the day with optimisations
+        #[arg(long)]
+        release: bool,
+        /// How often to check for changes
+        #[arg(long, value_name = "SECONDS", default_value = "0.5", value_parser = parse_seconds)]
+        interval: Duration,
+    },
+    /// Show a day's recorded timings over time, flagging runs much slower
+    /// than its best
+    History {
+        day: DayArg,
+        /// Flag runs whose total time is more than this percentage over the best
+        #[arg(long, value_name = "PERCENT", default_value_t = 20.0)]
+        threshold: f64,
+    },
+    /// Browse every registered day in an interactive terminal dashboard,
+    /// running them and watching their logs
+    #[command(alias = "tui")]
+    Dashboard {
+        /// Start out running the examples rather than the puzzle inputs
+        #[arg(long)]
+        example: bool,
+        /// Give up on a day after this many seconds and report it as TIMEOUT
+        #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+        timeout: Option<Duration>,
+    },
+    /// List the registered days
+    List,
+}
+
+#[derive(Debug, Args)]
+struct ServerOptions {
+    /// Session cookie of a logged in Advent of Code account
+    #[arg(long, env = "AOC_SESSION", hide_env_values = true)]
+    session: Option<String>,
+    /// Server to talk to, e.g. a local stub for testing
+    #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+    base_url: String,
+}
+
+fn cache_dir() -> Option<PathBuf> {
+    let dir = fetch::default_cache_dir();
+
+    if dir.is_none() {
+        eprintln!("No cache directory, set AOC_CACHE_DIR or pass --cache-dir");
+    }
+
+    return dir;
+}
+
+#[derive(Debug, Args)]
+struct RunOptions {
+    /// Save the computed answers to `./dayN/answers.toml`
+    #[arg(long)]
+    record: bool,
+    /// Benchmark each stage over N timed runs, after a short warm-up
+    #[arg(long, value_name = "N", value_parser = clap::value_parser!(u32).range(1..))]
+    bench: Option<u32>,
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t)]
+    format: Format,
+    /// Give up on a day after this many seconds and report it as TIMEOUT
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    timeout: Option<Duration>,
+    /// Don't append the timings to `./dayN/history.jsonl`
+    #[arg(long)]
+    no_history: bool,
+}
+
+fn parse_seconds(s: &str) -> Result<Duration, String> {
+    match s.parse::<f64>() {
+        Ok(secs) if secs > 0.0 && secs.is_finite() => Ok(Duration::from_secs_f64(secs)),
+        _ => Err(format!(
+            "expected a positive number of seconds, got `{}`",
+            s
+        )),
+    }
+}
+
+impl From<RunOptions> for runner::Options {
+    fn from(value: RunOptions) -> Self {
+        return Self {
+            record: value.record,
+            bench: value.bench.map(|n| n as usize),
+            timeout: value.timeout,
+            history: !value.no_history,
+        };
+    }
+}
+
+/// A day as given on the command line, with or without its year.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct DayArg {
+    year: Option<u16>,
+    day: u8,
+}
+
+impl DayArg {
+    fn resolve(&self, year: u16) -> Day {
+        Day::new(self.year.unwrap_or(year), self.day)
+    }
+}
+
+impl FromStr for DayArg {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.contains('/') {
+            true => s.parse::<Day>().map(|day| Self {
+                year: Some(day.year),
+                day: day.day,
+            }),
+            false => match parse_day_number(s) {
+                Some(day) => day.map(|day| Self { year: None, day }),
+                None => Err(format!("expected a day like `5` or `2023/5`, got `{}`", s)),
+            },
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Selection {
+    All,
+    Year(u16),
+    Day(DayArg),
+}
+
+impl FromStr for Selection {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        // Days only go up to 25, so four digits can only be a year.
+        if let Some(year) = parse_year(s) {
+            return year.map(Self::Year);
+        }
+
+        match s {
+            "all" => Ok(Self::All),
+            _ if s.contains('/') => s.parse().map(Self::Day),
+            _ => match parse_day_number(s) {
+                Some(day) => day.map(|day| Self::Day(DayArg { year: None, day })),
+                None => Err(format!(
+                    "expected a day like `5` or `2023/5`, a year or `all`, got `{}`",
+                    s
+                )),
+            },
+        }
+    }
+}
+
+/// `--example` with no value runs every example.
+fn example_name(name: &str) -> Option<&str> {
+    match name {
+        "" => None,
+        _ => Some(name),
+    }
+}
+
+/// Solves each input in turn, returning `false` if any could not be read or
+/// disagreed with a known answer.
+fn run_sources(
+    day: Day,
+    solver: &dyn Solver,
+    sources: &[Source],
+    options: &runner::Options,
+    printer: &mut Printer,
+) -> bool {
+    let mut passed = true;
+
+    for (source, name) in sources.iter().zip(input::names(sources)) {
+        let result = match source.read() {
+            Ok(input) => {
+                let input = Input { name, ..input };
+                runner::run(day, solver, &input, options).map_err(|e| e.to_string())
+            }
+            Err(e) => Err(e.to_string()),
+        };
+
+        match result {
+            Ok(result) => {
+                printer.print(&result);
+                passed &= result.passed();
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", day, e);
+                printer.error(day, &e);
+                passed = false;
+            }
+        }
+    }
+
+    return passed;
+}
+
+fn fetch(day: Day, server: ServerOptions) -> ExitCode {
+    let Some(cache_dir) = cache_dir() else {
+        return ExitCode::FAILURE;
+    };
+
+    let fetcher = Fetcher {
+        base_url: server.base_url,
+        session: server.session,
+        cache_dir,
+    };
+
+    match fetcher.fetch(day.year, day.day) {
+        Ok(fetched) => {
+            if fetched.cached {
+                eprintln!("Day {} input is already cached", day);
+            }
+            println!("{}", fetched.path.display());
+            return ExitCode::SUCCESS;
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+/// The part's answer for the default input, solved by the runner exactly as
+/// `aoc run` would solve it.
+fn solve_part(
+    day: Day,
+    solver: &dyn Solver,
+    part: u8,
+    timeout: Option<Duration>,
+) -> Result<String, String> {
+    let input = Source::File(input::default_input_path(day))
+        .read()
+        .map_err(|e| e.to_string())?;
+
+    return answer_part(day, solver, &input, part, timeout);
+}
+
+fn answer_part(
+    day: Day,
+    solver: &dyn Solver,
+    input: &Input,
+    part: u8,
+    timeout: Option<Duration>,
+) -> Result<String, String> {
+    let options = runner::Options {
+        timeout,
+        ..runner::Options::default()
+    };
+    let result = runner::run(day, solver, input, &options).map_err(|e| e.to_string())?;
+    let part = &result.parts[usize::from(part) - 1];
+
+    return match (&part.answer, &part.status) {
+        (Some(answer), _) => Ok(answer.to_string()),
+        (None, Some(Status::Timeout)) => Err(format!("Part {} timed out", part.part)),
+        (None, _) => Err(format!("No answer for part {}", part.part)),
+    };
+}
+
+/// Submits `answer` and keeps it as the known answer when it is right.
+fn submit(day: Day, part: u8, answer: &str, server: ServerOptions) -> ExitCode {
+    let Some(cache_dir) = cache_dir() else {
+        return ExitCode::FAILURE;
+    };
+
+    let submitter = Submitter {
+        base_url: server.base_url,
+        session: server.session,
+        cache_dir,
+    };
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .expect("Clock is before the Unix epoch")
+        .as_secs();
+
+    println!("Day {} Part {}: submitting {}", day, part, answer);
+
+    match submitter.submit(day.year, day.day, part, answer, now) {
+        Ok(Outcome::Correct) => {
+            println!("{}", Outcome::Correct);
+
+            let path = default_answers_path(day);
+            let recorded = KnownAnswers::load(&path).and_then(|mut answers| {
+                let recorded = match answer.parse::<i128>() {
+                    Ok(value) => Answer::from(value),
+                    Err(_) => Answer::from(answer),
+                };
+                answers.record("input", part, &recorded);
+                answers.save(&path)
+            });
+
+            match recorded {
+                Ok(()) => println!("Recorded answer to {}", path.display()),
+                Err(e) => eprintln!("Could not record answer to {}: {}", path.display(), e),
+            }
+
+            return ExitCode::SUCCESS;
+        }
+        Ok(outcome) => {
+            println!("{}", outcome);
+            return ExitCode::FAILURE;
+        }
+        Err(e) => {
+            eprintln!("Day {}: {}", day, e);
+            return ExitCode::FAILURE;
+        }
+    }
+}
+
+/// Entry point for the `aoc` binary.
+pub fn main(registry: Registry) -> ExitCode {
+    return dispatch(registry, Cli::parse());
+}
+
+/// Entry point for a single day's binary, where `dayN [ARGS]` runs like
+/// `aoc run YYYY/N [ARGS]`.
+pub fn day_main(register: Register) -> ExitCode {
+    let registry = Registry::from(&[register][..]);
+    let Some((day, _)) = registry.days().next() else {
+        eprintln!("No day was registered");
+        return ExitCode::FAILURE;
+    };
+
+    let mut args = env::args_os();
+    let args = args
+        .next()
+        .into_iter()
+        .chain(["run".into(), day.to_string().into()])
+        .chain(args);
+
+    return dispatch(registry, Cli::parse_from(args));
+}
+
+fn dispatch(registry: Registry, cli: Cli) -> ExitCode {
+    let year = cli.year.or(registry.latest_year()).unwrap_or(ROOT_YEAR);
+
+    log::set_filter(
+        cli.log
+            .unwrap_or_else(|| Filter::new(Some(Level::from_verbosity(cli.verbose))))
+            .in_year(year),
+    );
+
+    if let Some(dir) = cli.cache_dir {
+        fetch::set_cache_dir(dir);
+    }
+
+    match cli.command {
+        Command::Fetch { day, server } => return fetch(day.resolve(year), server),
+        Command::Submit {
+            day,
+            part,
+            answer,
+            timeout,
+            server,
+        } => {
+            let day = day.resolve(year);
+            let answer = match (answer, registry.get(day)) {
+                (Some(answer), _) => answer,
+                (None, None) => {
+                    eprintln!("Day {} is not registered", day);
+                    return ExitCode::FAILURE;
+                }
+                (None, Some(solver)) => match solve_part(day, solver, part, timeout) {
+                    Ok(answer) => answer,
+                    Err(e) => {
+                        eprintln!("Day {}: {}", day, e);
+                        return ExitCode::FAILURE;
+                    }
+                },
+            };
+
+            return submit(day, part, &answer, server);
+        }
+        Command::New { day } => match scaffold::new_day(Path::new("."), day.resolve(year)) {
+            Ok(files) => {
+                for file in files {
+                    println!("Wrote {}", file.display());
+                }
+            }
+            Err(e) => {
+                eprintln!("Day {}: {}", day.resolve(year), e);
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Gen { day, seed, size } => match registry.generator(day.resolve(year)) {
+            Some(generate) => print!("{}", generate(&mut Rng::new(seed), size as usize)),
+            None => {
+                eprintln!("Day {} has no input generator", day.resolve(year));
+                return ExitCode::FAILURE;
+            }
+        },
+        Command::Watch {
+            day,
+            inputs,
+            example,
+            release,
+            interval,
+        } => {
+            let day = day.resolve(year);
+            let mut paths = vec![day.dir()];
+            let mut args: Vec<OsString> = inputs.iter().map(OsString::from).collect();
+
+            match &example {
+                Some(name) => args.extend(["--example".into(), name.into()]),
+                None if inputs.is_empty() => paths.push(input::default_input_path(day)),
+                None => paths.extend(inputs),
+            }
+
+            watch::watch(day, &paths, &args, release, interval);
+        }
+        Command::History { day, threshold } => {
+            let day = day.resolve(year);
+            let path = history::default_history_path(day);
+            match History::load(&path) {
+                Ok(history) if history.entries().is_empty() => {
+                    println!("Day {} has no recorded runs", day)
+                }
+                Ok(history) => println!("Day {}\n{}", day, history.render(threshold)),
+                Err(e) => {
+                    eprintln!("Could not read {}: {}", path.display(), e);
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Dashboard { example, timeout } => {
+            let options = runner::Options {
+                timeout,
+                history: true,
+                ..runner::Options::default()
+            };
+
+            if let Err(e) = dashboard::dashboard(registry, example, options) {
+                eprintln!("Dashboard failed: {}", e);
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::List => {
+            for (day, _) in registry.days() {
+                println!("Day {}", day);
+            }
+        }
+        Command::Run {
+            day: Selection::All | Selection::Year(_),
+            inputs,
+            inline,
+            ..
+        } if !inputs.is_empty() || !inline.is_empty() => {
+            eprintln!("Inputs can only be given when running a single day");
+            return ExitCode::FAILURE;
+        }
+        Command::Run {
+            day: selection @ (Selection::All | Selection::Year(_)),
+            options,
+            example,
+            ..
+        } => {
+            let days: Vec<(Day, &dyn Solver)> = registry
+                .days()
+                .filter(|(day, _)| {
+                    selection == Selection::All || selection == Selection::Year(day.year)
+                })
+                .collect();
+
+            if days.is_empty() {
+                eprintln!("No days are registered");
+                return ExitCode::FAILURE;
+            }
+
+            let mut printer = Printer::summary(options.format);
+            let options = options.into();
+            let mut passed = true;
+
+            for (day, solver) in days {
+                let sources = match &example {
+                    Some(name) => input::example_sources(day, example_name(name)),
+                    None => Ok(vec![Source::File(input::default_input_path(day))]),
+                };
+
+                match sources {
+                    Ok(sources) => {
+                        passed &= run_sources(day, solver, &sources, &options, &mut printer)
+                    }
+                    Err(e) => {
+                        eprintln!("Day {}: {}", day, e);
+                        printer.error(day, &e.to_string());
+                        passed = false;
+                    }
+                }
+            }
+
+            printer.finish();
+
+            if !passed {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Run {
+            day: Selection::Day(day),
+            inputs,
+            inline,
+            example,
+            options,
+        } => match registry.get(day.resolve(year)) {
+            Some(solver) => {
+                let day = day.resolve(year);
+                let sources = match (inputs.is_empty(), inline.is_empty(), &example) {
+                    (true, _, Some(name)) => input::example_sources(day, example_name(name)),
+                    (true, false, None) => Ok(vec![]),
+                    _ => input::sources(day, &inputs),
+                };
+
+                let mut sources = match sources {
+                    Ok(sources) => sources,
+                    Err(e) => {
+                        eprintln!("{}", e);
+                        return ExitCode::FAILURE;
+                    }
+                };
+                sources.extend(inline.into_iter().map(Source::Inline));
+
+                let mut printer = Printer::new(options.format);
+                let passed = run_sources(day, solver, &sources, &options.into(), &mut printer);
+                printer.finish();
+
+                if !passed {
+                    return ExitCode::FAILURE;
+                }
+            }
+            None => {
+                eprintln!("Day {} is not registered", day.resolve(year));
+                return ExitCode::FAILURE;
+            }
+        },
+    }
+
+    return ExitCode::SUCCESS;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{answer_part, parse_seconds, DayArg, Selection};
+    use crate::{CancelToken, Day, Input, Result, Solver};
+
+    #[test]
+    fn test_parse_selection() {
+        let day = |year, day| Selection::Day(DayArg { year, day });
+
+        assert_eq!("all".parse(), Ok(Selection::All));
+        assert_eq!("5".parse(), Ok(day(None, 5)));
+        assert_eq!("2023/5".parse(), Ok(day(Some(2023), 5)));
+        assert_eq!("2024".parse(), Ok(Selection::Year(2024)));
+        assert!("five".parse::<Selection>().is_err());
+        assert!("2023/".parse::<Selection>().is_err());
+
+        assert!("0".parse::<Selection>().is_err());
+        assert!("99".parse::<DayArg>().is_err());
+        assert!("2023/26".parse::<DayArg>().is_err());
+        assert!("0005".parse::<Selection>().is_err());
+        assert!("9999/5".parse::<Selection>().is_ok());
+    }
+
+    #[test]
+    fn test_resolve_day() {
+        let bare: DayArg = "5".parse().unwrap();
+        let full: DayArg = "2023/5".parse().unwrap();
+
+        assert_eq!(bare.resolve(2024), Day::new(2024, 5));
+        assert_eq!(full.resolve(2024), Day::new(2023, 5));
+    }
+
+    #[test]
+    fn test_answer_part() {
+        let day = Day::new(2023, 0);
+        let input = Input {
+            name: "input".to_string(),
+            contents: "1\r\n2  \r\n\r\n".to_string(),
+            example: false,
+        };
+
+        // Submitted answers come from the normalised input, as `run` shows.
+        let carriage_returns: fn(&str) -> Result =
+            |input| (Some(input.matches('\r').count().into()), None);
+        let solver = &carriage_returns as &dyn Solver;
+        assert_eq!(answer_part(day, solver, &input, 1, None), Ok("0".into()));
+        assert!(answer_part(day, solver, &input, 2, None).is_err());
+
+        let spin: fn(&str) -> Result = |_| {
+            let cancel = CancelToken::current();
+            while !cancel.is_cancelled() {}
+            return (None, None);
+        };
+        let timeout = Some(Duration::from_millis(10));
+        assert_eq!(
+            answer_part(day, &spin as &dyn Solver, &input, 1, timeout),
+            Err("Part 1 timed out".to_string())
+        );
+    }
+
+    #[test]
+    fn test_parse_seconds() {
+        assert_eq!(parse_seconds("2"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_seconds("0.5"), Ok(Duration::from_millis(500)));
+        assert!(parse_seconds("0").is_err());
+        assert!(parse_seconds("soon").is_err());
+    }
+}
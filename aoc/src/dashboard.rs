@@ -0,0 +1,605 @@
+use std::{
+    io::{self, Stdout},
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    sync::{
+        mpsc::{self, Receiver, Sender},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+use crossterm::{
+    event::{self, Event, KeyCode, KeyEventKind, KeyModifiers},
+    execute,
+    terminal::{disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen},
+};
+use ratatui::{
+    backend::CrosstermBackend,
+    layout::{Constraint, Direction, Layout},
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{Block, Borders, Paragraph, Row, Table, TableState},
+    Frame, Terminal,
+};
+
+use crate::{
+    answers::default_answers_path,
+    history::default_history_path,
+    input::{self, Source},
+    log,
+    runner::{self, DayResult},
+    summary, Day, History, KnownAnswers, Registry,
+};
+
+/// How many log lines are kept for the log pane.
+const LOG_LINES: usize = 1000;
+
+/// How long to wait for a key before picking up finished days and new logs.
+const TICK: Duration = Duration::from_millis(100);
+
+/// Statuses from most to least worth seeing, as in the summary table.
+const STATUS_ORDER: [&str; 4] = ["FAIL", "TIMEOUT", "NEW", "PASS"];
+
+#[derive(Debug, Clone, Default)]
+enum DayState {
+    #[default]
+    NotRun,
+    /// Not run yet, but answered and timed by an earlier run of the input.
+    Saved {
+        answers: [Option<String>; 2],
+        total: Option<Duration>,
+    },
+    Queued,
+    Running,
+    /// One result per input.
+    Done(Vec<DayResult>),
+    Error(String),
+}
+
+impl DayState {
+    fn status(&self) -> String {
+        match self {
+            DayState::NotRun => "-".to_string(),
+            DayState::Saved { .. } => "SAVED".to_string(),
+            DayState::Queued => "QUEUED".to_string(),
+            DayState::Running => "RUNNING".to_string(),
+            DayState::Error(_) => "ERROR".to_string(),
+            DayState::Done(results) => {
+                let statuses: Vec<String> = results.iter().map(summary::status).collect();
+                STATUS_ORDER
+                    .into_iter()
+                    .find(|s| statuses.iter().any(|status| status == s))
+                    .unwrap_or("-")
+                    .to_string()
+            }
+        }
+    }
+}
+
+/// What earlier runs of the day's puzzle input left behind: its known
+/// answers and the total time of its latest recorded run.
+fn saved(answers: &Path, history: &Path) -> DayState {
+    // A file that can't be read is reported once the day is run.
+    let known = KnownAnswers::load(answers).unwrap_or_default();
+    let answers = [1, 2].map(|part| known.get("input", part));
+    let total = History::load(history).ok().and_then(|history| {
+        let latest = history.entries().iter().rfind(|e| e.input == "input")?;
+        Some(latest.total())
+    });
+
+    return match (&answers, total) {
+        ([None, None], None) => DayState::NotRun,
+        _ => DayState::Saved { answers, total },
+    };
+}
+
+enum Update {
+    Started(Day),
+    Finished(Day, DayState),
+}
+
+/// Runs each requested day in turn, off the UI thread so that the screen
+/// keeps updating while a slow day is solved.
+fn worker(
+    registry: Arc<Registry>,
+    options: runner::Options,
+    requests: Receiver<(Day, bool)>,
+    updates: Sender<Update>,
+) {
+    for (day, example) in requests {
+        let Some(solver) = registry.get(day) else {
+            continue;
+        };
+        let _ = updates.send(Update::Started(day));
+
+        let sources = match example {
+            true => input::example_sources(day, None),
+            false => Ok(vec![Source::File(input::default_input_path(day))]),
+        };
+
+        // A panicking solver is reported like any other error, rather than
+        // taking the worker and every later run down with it.
+        let results = panic::catch_unwind(AssertUnwindSafe(|| {
+            sources
+                .map_err(|e| e.to_string())?
+                .iter()
+                .map(|source| {
+                    let input = source.read().map_err(|e| e.to_string())?;
+                    runner::run(day, solver, &input, &options).map_err(|e| e.to_string())
+                })
+                .collect::<Result<Vec<DayResult>, String>>()
+        }));
+
+        let state = match results {
+            Ok(Ok(results)) => DayState::Done(results),
+            Ok(Err(e)) => DayState::Error(e),
+            Err(_) => DayState::Error("Solver panicked, see the message on quitting".to_string()),
+        };
+        let _ = updates.send(Update::Finished(day, state));
+    }
+}
+
+struct Dashboard {
+    days: Vec<(Day, DayState)>,
+    table: TableState,
+    logs: Vec<String>,
+    /// Run the examples rather than the puzzle inputs.
+    example: bool,
+}
+
+impl Dashboard {
+    fn new(registry: &Registry, example: bool) -> Self {
+        let days = registry
+            .days()
+            .map(|(day, _)| {
+                let state = saved(&default_answers_path(day), &default_history_path(day));
+                (day, state)
+            })
+            .collect();
+
+        return Self {
+            days,
+            table: TableState::default().with_selected(Some(0)),
+            logs: Vec::new(),
+            example,
+        };
+    }
+
+    fn select(&mut self, offset: isize) {
+        let last = self.days.len().saturating_sub(1);
+        let selected = self.table.selected().unwrap_or(0);
+        self.table
+            .select(Some(selected.saturating_add_signed(offset).min(last)));
+    }
+
+    fn selected(&self) -> Option<&(Day, DayState)> {
+        self.days.get(self.table.selected()?)
+    }
+
+    /// Queues `day`, unless it is already waiting or running.
+    fn queue(&mut self, day: Day, requests: &Sender<(Day, bool)>) {
+        let Some((_, state)) = self.days.iter_mut().find(|(d, _)| *d == day) else {
+            return;
+        };
+
+        if !matches!(state, DayState::Queued | DayState::Running) {
+            *state = DayState::Queued;
+            let _ = requests.send((day, self.example));
+        }
+    }
+
+    fn update(&mut self, update: Update) {
+        let (day, new_state) = match update {
+            Update::Started(day) => (day, DayState::Running),
+            Update::Finished(day, state) => (day, state),
+        };
+
+        if let Some((_, state)) = self.days.iter_mut().find(|(d, _)| *d == day) {
+            *state = new_state;
+        }
+    }
+
+    fn log(&mut self, line: String) {
+        self.logs.push(line);
+        if self.logs.len() > LOG_LINES {
+            self.logs.drain(..self.logs.len() - LOG_LINES);
+        }
+    }
+
+    fn draw(&mut self, frame: &mut Frame) {
+        let [main, logs, help] = *Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(8),
+                Constraint::Length(12),
+                Constraint::Length(1),
+            ])
+            .split(frame.size())
+        else {
+            return;
+        };
+        let [days, details] = *Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
+            .split(main)
+        else {
+            return;
+        };
+
+        let rows = self.days.iter().map(|(day, state)| {
+            let (answers, total) = match state {
+                DayState::Done(results) => {
+                    let first = &results[0];
+                    let answers = first.parts.each_ref().map(|p| match &p.answer {
+                        Some(answer) => answer.to_string(),
+                        None => "-".to_string(),
+                    });
+                    let total: Duration = results.iter().map(DayResult::total_time).sum();
+                    (answers, format!("{:.2?}", total))
+                }
+                DayState::Saved { answers, total } => (
+                    answers
+                        .clone()
+                        .map(|answer| answer.unwrap_or_else(|| "-".to_string())),
+                    total.map_or("-".to_string(), |total| format!("{:.2?}", total)),
+                ),
+                _ => (["-".to_string(), "-".to_string()], "-".to_string()),
+            };
+            let [part_1, part_2] = answers;
+
+            Row::new([day.to_string(), part_1, part_2, total, state.status()])
+        });
+
+        let table = Table::new(
+            rows,
+            [
+                Constraint::Length(8),
+                Constraint::Min(10),
+                Constraint::Min(10),
+                Constraint::Length(10),
+                Constraint::Length(8),
+            ],
+        )
+        .header(
+            Row::new(["Day", "Answer 1", "Answer 2", "Total", "Status"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title(match self.example {
+                    true => " Days (examples) ",
+                    false => " Days ",
+                }),
+        )
+        // Reversed video rather than colours, which every terminal and
+        // multiplexer on the other end of an SSH session can show.
+        .highlight_style(Style::default().add_modifier(Modifier::REVERSED));
+
+        frame.render_stateful_widget(table, days, &mut self.table);
+
+        let (title, lines) = match self.selected() {
+            Some((day, state)) => (format!(" Day {} ", day), details_lines(state)),
+            None => (" No days registered ".to_string(), vec![]),
+        };
+        frame.render_widget(
+            Paragraph::new(lines).block(Block::default().borders(Borders::ALL).title(title)),
+            details,
+        );
+
+        let visible = logs.height.saturating_sub(2) as usize;
+        let tail: Vec<Line> = self.logs[self.logs.len().saturating_sub(visible)..]
+            .iter()
+            .map(|line| Line::from(line.as_str()))
+            .collect();
+        frame.render_widget(
+            Paragraph::new(tail).block(Block::default().borders(Borders::ALL).title(" Log ")),
+            logs,
+        );
+
+        frame.render_widget(
+            Paragraph::new(
+                " j/k move  enter run  a run all  e toggle examples  c clear log  q quit",
+            ),
+            help,
+        );
+    }
+}
+
+fn details_lines(state: &DayState) -> Vec<Line<'static>> {
+    let results = match state {
+        DayState::NotRun => return vec![Line::from("Not run yet, press enter to run")],
+        DayState::Saved { answers, total } => {
+            let mut lines = vec![Line::from("From an earlier run, press enter to run")];
+            for (part, answer) in (1..).zip(answers) {
+                let answer = answer.as_deref().unwrap_or("-");
+                lines.push(Line::from(format!("  Part {}: {}", part, answer)));
+            }
+            if let Some(total) = total {
+                lines.push(Line::from(format!("  Total: {:.2?}", total)));
+            }
+            return lines;
+        }
+        DayState::Queued => return vec![Line::from("Waiting for another day to finish")],
+        DayState::Running => return vec![Line::from("Running...")],
+        DayState::Error(e) => return vec![Line::from(e.clone())],
+        DayState::Done(results) => results,
+    };
+
+    let mut lines = vec![];
+
+    for result in results {
+        lines.push(Line::styled(
+            result.input.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+
+        for part in &result.parts {
+            let answer = part
+                .answer
+                .as_ref()
+                .map_or("-".to_string(), |a| a.to_string());
+            let status = match (part.status, &part.expected) {
+                (Some(status @ crate::Status::Fail), Some(expected)) => {
+                    format!(" ({}, expected {})", status, expected)
+                }
+                (Some(status), _) => format!(" ({})", status),
+                (None, _) => String::new(),
+            };
+            lines.push(Line::from(format!(
+                "  Part {}: {}{}",
+                part.part, answer, status
+            )));
+        }
+
+        for (stage, time) in &result.timings {
+            lines.push(Line::from(format!("  {}: {:.2?}", stage, time)));
+        }
+        lines.push(Line::from(format!("  Total: {:.2?}", result.total_time())));
+    }
+
+    return lines;
+}
+
+/// Owns the terminal while the dashboard is up, and hands it back in its
+/// normal state however the dashboard exits, panics included.
+struct Screen {
+    terminal: Terminal<CrosstermBackend<Stdout>>,
+}
+
+impl Screen {
+    fn enter() -> io::Result<Self> {
+        enable_raw_mode()?;
+        execute!(io::stdout(), EnterAlternateScreen)?;
+
+        return Ok(Self {
+            terminal: Terminal::new(CrosstermBackend::new(io::stdout()))?,
+        });
+    }
+}
+
+impl Drop for Screen {
+    fn drop(&mut self) {
+        let _ = disable_raw_mode();
+        let _ = execute!(self.terminal.backend_mut(), LeaveAlternateScreen);
+        let _ = self.terminal.show_cursor();
+    }
+}
+
+/// Holds back panic messages while the dashboard owns the terminal, and
+/// writes them to stderr once it has been handed back, even with logging off.
+struct Panics {
+    messages: Arc<Mutex<Vec<String>>>,
+}
+
+impl Panics {
+    fn hold() -> Self {
+        let messages = Arc::new(Mutex::new(Vec::new()));
+        let held = Arc::clone(&messages);
+        panic::set_hook(Box::new(move |info| {
+            crate::error!("{}", info);
+            if let Ok(mut held) = held.lock() {
+                held.push(info.to_string());
+            }
+        }));
+
+        return Self { messages };
+    }
+}
+
+impl Drop for Panics {
+    fn drop(&mut self) {
+        let _ = panic::take_hook();
+        if let Ok(mut messages) = self.messages.lock() {
+            for message in messages.drain(..) {
+                eprintln!("{}", message);
+            }
+        }
+    }
+}
+
+fn event_loop(
+    screen: &mut Screen,
+    dashboard: &mut Dashboard,
+    requests: Sender<(Day, bool)>,
+    updates: Receiver<Update>,
+    logs: Receiver<String>,
+) -> io::Result<()> {
+    loop {
+        for line in logs.try_iter() {
+            dashboard.log(line);
+        }
+        for update in updates.try_iter() {
+            dashboard.update(update);
+        }
+
+        screen.terminal.draw(|frame| dashboard.draw(frame))?;
+
+        if !event::poll(TICK)? {
+            continue;
+        }
+
+        let Event::Key(key) = event::read()? else {
+            continue;
+        };
+        if key.kind != KeyEventKind::Press {
+            continue;
+        }
+
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => break,
+            KeyCode::Char('q') | KeyCode::Esc => break,
+            KeyCode::Up | KeyCode::Char('k') => dashboard.select(-1),
+            KeyCode::Down | KeyCode::Char('j') => dashboard.select(1),
+            KeyCode::PageUp => dashboard.select(-10),
+            KeyCode::PageDown => dashboard.select(10),
+            KeyCode::Home | KeyCode::Char('g') => dashboard.select(isize::MIN),
+            KeyCode::End | KeyCode::Char('G') => dashboard.select(isize::MAX),
+            KeyCode::Enter | KeyCode::Char('r') => {
+                if let Some(&(day, _)) = dashboard.selected() {
+                    dashboard.queue(day, &requests);
+                }
+            }
+            KeyCode::Char('a') => {
+                let days: Vec<Day> = dashboard.days.iter().map(|(day, _)| *day).collect();
+                for day in days {
+                    dashboard.queue(day, &requests);
+                }
+            }
+            KeyCode::Char('e') => dashboard.example = !dashboard.example,
+            KeyCode::Char('c') => dashboard.logs.clear(),
+            _ => {}
+        }
+    }
+
+    return Ok(());
+}
+
+/// Shows every registered day in an interactive table, running the selected
+/// day, or all of them, in the background and streaming their logs below.
+/// Only needs a plain terminal, so it works just as well over SSH.
+pub fn dashboard(registry: Registry, example: bool, options: runner::Options) -> io::Result<()> {
+    let registry = Arc::new(registry);
+    let mut dashboard = Dashboard::new(&registry, example);
+
+    let (log_sender, logs) = mpsc::channel();
+    let (requests, request_receiver) = mpsc::channel();
+    let (update_sender, updates) = mpsc::channel();
+
+    // Anything written to stderr would be drawn over the screen, so logs go
+    // to the log pane instead, and panic messages wait until it is gone.
+    log::capture(Some(log_sender));
+    let panics = Panics::hold();
+
+    // The worker is left behind on quitting, as a day can't be stopped
+    // part way, and ends with the process.
+    let worker_registry = Arc::clone(&registry);
+    thread::spawn(move || worker(worker_registry, options, request_receiver, update_sender));
+
+    let result = Screen::enter()
+        .and_then(|mut screen| event_loop(&mut screen, &mut dashboard, requests, updates, logs));
+
+    // The screen is restored by now, also when the event loop panicked, as
+    // it is dropped before `panics`.
+    drop(panics);
+    log::capture(None);
+
+    return result;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs, sync::mpsc, time::Duration};
+
+    use ratatui::{backend::TestBackend, Terminal};
+
+    use super::{saved, Dashboard, DayState, Update};
+    use crate::{Day, Entry, History, Registry};
+
+    fn registry() -> Registry {
+        let mut registry = Registry::new();
+        registry.register(Day::new(2023, 1), |_| (Some(142.into()), None));
+        registry.register(Day::new(2023, 2), |_| (None, None));
+        return registry;
+    }
+
+    #[test]
+    fn test_queue_and_update() {
+        let mut dashboard = Dashboard::new(&registry(), false);
+        let (requests, queued) = mpsc::channel();
+
+        dashboard.select(5);
+        assert_eq!(dashboard.table.selected(), Some(1));
+        dashboard.select(-5);
+        assert_eq!(dashboard.table.selected(), Some(0));
+
+        let day = Day::new(2023, 1);
+        dashboard.queue(day, &requests);
+        dashboard.queue(day, &requests);
+        assert_eq!(queued.try_iter().collect::<Vec<_>>(), [(day, false)]);
+        assert_eq!(dashboard.days[0].1.status(), "QUEUED");
+
+        dashboard.update(Update::Started(day));
+        assert_eq!(dashboard.days[0].1.status(), "RUNNING");
+        dashboard.update(Update::Finished(day, DayState::Error("missing".into())));
+        assert_eq!(dashboard.days[0].1.status(), "ERROR");
+    }
+
+    #[test]
+    fn test_saved() {
+        let dir = std::env::temp_dir().join(format!("aoc-dashboard-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let answers = dir.join("answers.toml");
+        let history = dir.join("history.jsonl");
+
+        assert_eq!(saved(&answers, &history).status(), "-");
+
+        fs::write(&answers, "[input]\npart1 = 142\n\n[example]\npart2 = 1\n").unwrap();
+        for (input, total) in [("input", 5), ("example", 1), ("input", 3)] {
+            let entry = Entry {
+                timestamp: 0,
+                commit: None,
+                profile: "debug".to_string(),
+                input: input.to_string(),
+                timings_ns: BTreeMap::from([("total".to_string(), total)]),
+            };
+            History::append(&history, &entry).unwrap();
+        }
+
+        let state = saved(&answers, &history);
+        fs::remove_dir_all(&dir).unwrap();
+
+        assert_eq!(state.status(), "SAVED");
+        let DayState::Saved { answers, total } = state else {
+            unreachable!();
+        };
+        assert_eq!(answers, [Some("142".to_string()), None]);
+        assert_eq!(total, Some(Duration::from_nanos(3)));
+    }
+
+    #[test]
+    fn test_draw() {
+        let mut dashboard = Dashboard::new(&registry(), true);
+        dashboard.log("[day1 info] hello".to_string());
+
+        let mut terminal = Terminal::new(TestBackend::new(80, 24)).unwrap();
+        terminal.draw(|frame| dashboard.draw(frame)).unwrap();
+
+        let buffer = terminal.backend().buffer();
+        let screen: Vec<String> = (0..buffer.area.height)
+            .map(|y| {
+                (0..buffer.area.width)
+                    .map(|x| buffer.get(x, y).symbol())
+                    .collect()
+            })
+            .collect();
+
+        assert!(screen[0].contains("Days (examples)"));
+        assert!(screen[2].contains("2023/1"));
+        assert!(screen[3].contains("2023/2"));
+        assert!(screen.iter().any(|line| line.contains("[day1 info] hello")));
+        assert!(screen[23].contains("q quit"));
+    }
+}
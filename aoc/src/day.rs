@@ -0,0 +1,125 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// The year whose day crates sit directly in the workspace as `./dayN`, as
+/// they did before the runner knew about other years. Any other year keeps
+/// its days under `./YYYY/dayN`.
+pub const ROOT_YEAR: u16 = 2023;
+
+/// The year of the first Advent of Code.
+pub const FIRST_YEAR: u16 = 2015;
+
+/// A day of the month with a puzzle, from 1 to 25.
+pub(crate) fn parse_day_number(s: &str) -> Option<Result<u8, String>> {
+    let day = s.parse::<u8>().ok()?;
+
+    return match day {
+        1..=25 => Some(Ok(day)),
+        _ => Some(Err(format!(
+            "there is no day {}, puzzles run from day 1 to 25",
+            day
+        ))),
+    };
+}
+
+/// A year with puzzles, from [`FIRST_YEAR`] on.
+pub(crate) fn parse_year(s: &str) -> Option<Result<u16, String>> {
+    let year = s.parse::<u16>().ok().filter(|_| s.len() == 4)?;
+
+    return match year >= FIRST_YEAR {
+        true => Some(Ok(year)),
+        false => Some(Err(format!(
+            "there is no year {}, Advent of Code started in {}",
+            year, FIRST_YEAR
+        ))),
+    };
+}
+
+/// A single puzzle, ordered by year and then day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Day {
+    pub year: u16,
+    pub day: u8,
+}
+
+impl Day {
+    pub const fn new(year: u16, day: u8) -> Self {
+        return Self { year, day };
+    }
+
+    /// The day's crate, relative to the workspace root.
+    pub fn dir(&self) -> PathBuf {
+        match self.year {
+            ROOT_YEAR => PathBuf::from(format!("./day{}", self.day)),
+            year => PathBuf::from(format!("./{}/day{}", year, self.day)),
+        }
+    }
+
+    /// Name of the day's cargo package: `dayN`, or `dayN-YYYY` outside of
+    /// [`ROOT_YEAR`] so that every year can have a `day1`.
+    pub fn package(&self) -> String {
+        match self.year {
+            ROOT_YEAR => format!("day{}", self.day),
+            year => format!("day{}-{}", self.day, year),
+        }
+    }
+}
+
+/// `YYYY/D`, the form taken on the command line.
+impl Display for Day {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}", self.year, self.day)
+    }
+}
+
+impl FromStr for Day {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parsed = s
+            .split_once('/')
+            .and_then(|(year, day)| Some((parse_year(year)?, parse_day_number(day)?)));
+
+        return match parsed {
+            Some((year, day)) => Ok(Self::new(year?, day?)),
+            None => Err(format!("expected a day like `2023/5`, got `{}`", s)),
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::Day;
+
+    #[test]
+    fn test_parse_day() {
+        assert_eq!("2023/5".parse(), Ok(Day::new(2023, 5)));
+        assert_eq!(Day::new(2024, 12).to_string(), "2024/12");
+        assert!("5".parse::<Day>().is_err());
+        assert!("2023/five".parse::<Day>().is_err());
+        assert!("23/5".parse::<Day>().is_err());
+
+        assert_eq!(
+            "2023/0".parse::<Day>(),
+            Err("there is no day 0, puzzles run from day 1 to 25".to_string())
+        );
+        assert!("2023/26".parse::<Day>().is_err());
+        assert_eq!(
+            "1999/5".parse::<Day>(),
+            Err("there is no year 1999, Advent of Code started in 2015".to_string())
+        );
+    }
+
+    #[test]
+    fn test_day_layout() {
+        assert_eq!(Day::new(2023, 5).dir(), PathBuf::from("./day5"));
+        assert_eq!(Day::new(2023, 5).package(), "day5");
+        assert_eq!(Day::new(2024, 5).dir(), PathBuf::from("./2024/day5"));
+        assert_eq!(Day::new(2024, 5).package(), "day5-2024");
+    }
+}
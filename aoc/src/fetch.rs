@@ -0,0 +1,256 @@
+use std::{
+    env,
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    sync::RwLock,
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+pub(crate) const USER_AGENT: &str = "aoc-cli (Advent of Code runner)";
+
+static CACHE_DIR: RwLock<Option<PathBuf>> = RwLock::new(None);
+
+/// Caches in `dir` for the rest of the process, as `--cache-dir` asks, so
+/// that every command reads inputs from where `aoc fetch` saved them.
+pub fn set_cache_dir(dir: PathBuf) {
+    *CACHE_DIR.write().unwrap() = Some(dir);
+}
+
+/// Where fetched inputs are cached: the directory given to
+/// [`set_cache_dir`], else `$AOC_CACHE_DIR`, else `aoc` under
+/// `$XDG_CACHE_HOME` or `~/.cache`. Kept out of the source tree so that
+/// inputs are never committed.
+pub fn default_cache_dir() -> Option<PathBuf> {
+    return default_cache_dir_from(CACHE_DIR.read().unwrap().clone());
+}
+
+/// [`default_cache_dir`], with `dir` in place of the one given to
+/// [`set_cache_dir`].
+fn default_cache_dir_from(dir: Option<PathBuf>) -> Option<PathBuf> {
+    if let Some(dir) = dir {
+        return Some(dir);
+    }
+
+    if let Some(dir) = env::var_os("AOC_CACHE_DIR") {
+        return Some(PathBuf::from(dir));
+    }
+
+    env::var_os("XDG_CACHE_HOME")
+        .map(PathBuf::from)
+        .or_else(|| env::var_os("HOME").map(|home| Path::new(&home).join(".cache")))
+        .map(|dir| dir.join("aoc"))
+}
+
+/// Named `input.txt` so that cached inputs share the `[input]` known answers.
+pub fn cached_input_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{}", day))
+        .join("input.txt")
+}
+
+#[derive(Debug)]
+pub enum FetchError {
+    /// The input is not cached and there is no session token to download it with.
+    MissingSession,
+    /// The server answered with an error, e.g. for an expired session or a
+    /// puzzle that has not unlocked yet.
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for FetchError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::MissingSession => {
+                write!(f, "No session token, set AOC_SESSION or pass --session")
+            }
+            FetchError::Status(code, body) => {
+                write!(f, "Server responded with {}: {}", code, body.trim())
+            }
+            FetchError::Transport(error) => write!(f, "Request failed: {}", error),
+            FetchError::Io(path, error) => {
+                write!(f, "Could not write {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+/// A fetched input, and whether it was already in the cache.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetched {
+    pub path: PathBuf,
+    pub cached: bool,
+}
+
+/// Downloads puzzle inputs into the cache, at most once each.
+#[derive(Debug, Clone)]
+pub struct Fetcher {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+}
+
+impl Fetcher {
+    pub fn fetch(&self, year: u16, day: u8) -> Result<Fetched, FetchError> {
+        let path = cached_input_path(&self.cache_dir, year, day);
+
+        if path.exists() {
+            return Ok(Fetched { path, cached: true });
+        }
+
+        let session = self.session.as_ref().ok_or(FetchError::MissingSession)?;
+        let url = format!(
+            "{}/{}/day/{}/input",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .call();
+
+        let body = match response {
+            Ok(response) => response.into_string(),
+            Err(ureq::Error::Status(code, response)) => {
+                return Err(FetchError::Status(
+                    code,
+                    response.into_string().unwrap_or_default(),
+                ))
+            }
+            Err(error) => return Err(FetchError::Transport(error.to_string())),
+        };
+        let body = body.map_err(|e| FetchError::Transport(e.to_string()))?;
+
+        // Written to a temporary file first so an interrupted download is
+        // never mistaken for a cached input.
+        let to_error = |e| FetchError::Io(path.clone(), e);
+        let partial = path.with_extension("part");
+        fs::create_dir_all(path.parent().unwrap()).map_err(to_error)?;
+        fs::write(&partial, body).map_err(to_error)?;
+        fs::rename(&partial, &path).map_err(to_error)?;
+
+        return Ok(Fetched {
+            path,
+            cached: false,
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    use super::{cached_input_path, default_cache_dir_from, FetchError, Fetcher};
+    use crate::{input::input_path_in, Day};
+
+    /// Serves `status` and `body` to every request, counting them and
+    /// rejecting any without the expected session cookie.
+    fn stub_server(status: &'static str, body: &'static str) -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let mut stream = stream.unwrap();
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(|l| l.unwrap())
+                    .take_while(|l| !l.is_empty())
+                    .collect();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let status = match request
+                    .iter()
+                    .any(|l| l.eq_ignore_ascii_case("cookie: session=secret"))
+                {
+                    true => status,
+                    false => "400 Bad Request",
+                };
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        return (url, requests);
+    }
+
+    fn fetcher(name: &str, base_url: String) -> Fetcher {
+        let cache_dir =
+            std::env::temp_dir().join(format!("aoc-fetch-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+
+        return Fetcher {
+            base_url,
+            session: Some("secret".to_string()),
+            cache_dir,
+        };
+    }
+
+    #[test]
+    fn test_fetch_caches_input() {
+        let (url, requests) = stub_server("200 OK", "1,2,3\n");
+        let fetcher = fetcher("cache", url);
+
+        let first = fetcher.fetch(2023, 15).unwrap();
+        assert!(!first.cached);
+        assert_eq!(first.path, cached_input_path(&fetcher.cache_dir, 2023, 15));
+        assert_eq!(fs::read_to_string(&first.path).unwrap(), "1,2,3\n");
+
+        let second = fetcher.fetch(2023, 15).unwrap();
+        assert!(second.cached);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_fetch_errors_are_not_cached() {
+        let (url, requests) = stub_server("404 Not Found", "Not unlocked yet");
+        let mut fetcher = fetcher("errors", url);
+
+        let result = fetcher.fetch(2023, 25);
+        assert!(matches!(result, Err(FetchError::Status(404, _))));
+        assert!(!cached_input_path(&fetcher.cache_dir, 2023, 25).exists());
+
+        fetcher.session = None;
+        assert!(matches!(
+            fetcher.fetch(2023, 25),
+            Err(FetchError::MissingSession)
+        ));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_cache_dir_is_shared() {
+        let (url, _) = stub_server("200 OK", "1,2,3\n");
+        let fetcher = fetcher("shared", url);
+        let day = Day::new(2015, 3);
+
+        let cache_dir = default_cache_dir_from(Some(fetcher.cache_dir.clone()));
+        assert_eq!(cache_dir, Some(fetcher.cache_dir.clone()));
+
+        let fetched = fetcher.fetch(day.year, day.day).unwrap();
+        assert_eq!(input_path_in(day, cache_dir), fetched.path);
+    }
+}
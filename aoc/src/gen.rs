@@ -0,0 +1,199 @@
+use std::ops::Range;
+
+/// Writes a random puzzle input of roughly `size` lines, grid rows or items,
+/// whatever suits the day's format.
+pub type Generate = fn(&mut Rng, usize) -> String;
+
+/// A small seeded pseudo-random number generator (SplitMix64). Written out
+/// here rather than taken from a crate so that a seed keeps producing the
+/// same input on every platform and version.
+#[derive(Debug, Clone)]
+pub struct Rng {
+    state: u64,
+}
+
+impl Rng {
+    pub fn new(seed: u64) -> Self {
+        return Self { state: seed };
+    }
+
+    pub fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+
+        return z ^ (z >> 31);
+    }
+
+    /// A number in `range`, which must not be empty.
+    pub fn range(&mut self, range: Range<u64>) -> u64 {
+        assert!(!range.is_empty(), "Empty range {:?}", range);
+        return range.start + self.next_u64() % (range.end - range.start);
+    }
+
+    /// An index below `len`, which must not be zero.
+    pub fn below(&mut self, len: usize) -> usize {
+        return self.range(0..len as u64) as usize;
+    }
+
+    /// `true` with probability `p`.
+    pub fn chance(&mut self, p: f64) -> bool {
+        return ((self.next_u64() >> 11) as f64 / (1u64 << 53) as f64) < p;
+    }
+
+    pub fn choose<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        return &items[self.below(items.len())];
+    }
+
+    pub fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+const RING: [(isize, isize); 8] = [
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+    (-1, -1),
+];
+
+/// The corners of a random simple loop on a grid of `rows` by `cols` cells,
+/// as `(row, col)` points from `(0, 0)` to `(rows, cols)` in walking order,
+/// each a single step from the next. The loop never touches itself, and
+/// encloses a random blob of about half of the cells.
+pub fn simple_loop(rng: &mut Rng, rows: usize, cols: usize) -> Vec<(usize, usize)> {
+    assert!(rows > 0 && cols > 0, "Empty grid");
+
+    let mut inside = vec![vec![false; cols]; rows];
+    let is_inside = |inside: &[Vec<bool>], r: isize, c: isize| {
+        r >= 0
+            && c >= 0
+            && inside.get(r as usize).and_then(|row| row.get(c as usize)) == Some(&true)
+    };
+
+    inside[rows / 2][cols / 2] = true;
+    let mut cells = vec![(rows / 2, cols / 2)];
+    let target = (rows * cols / 2).max(1);
+
+    // Cells are only added where they touch the blob along a single run of
+    // their neighbours, and never by a corner alone, which keeps the blob
+    // free of holes and its outline a single loop.
+    for _ in 0..target * 20 {
+        if cells.len() >= target {
+            break;
+        }
+
+        let (r, c) = *rng.choose(&cells);
+        let (dr, dc) = RING[rng.below(4) * 2];
+        let (r, c) = (r as isize + dr, c as isize + dc);
+
+        if r < 0 || c < 0 || r >= rows as isize || c >= cols as isize || is_inside(&inside, r, c) {
+            continue;
+        }
+
+        let ring = RING.map(|(dr, dc)| is_inside(&inside, r + dr, c + dc));
+        let pinched = (1..8)
+            .step_by(2)
+            .any(|i| ring[i] && !ring[i - 1] && !ring[(i + 1) % 8]);
+        let runs = (0..8).filter(|&i| ring[i] && !ring[(i + 7) % 8]).count();
+
+        if !pinched && runs == 1 {
+            inside[r as usize][c as usize] = true;
+            cells.push((r as usize, c as usize));
+        }
+    }
+
+    // An edge between two corners is on the outline when exactly one of the
+    // cells either side of it is inside.
+    let edge = |(r, c): (usize, usize), (dr, dc): (isize, isize)| -> Option<(usize, usize)> {
+        let (nr, nc) = (r as isize + dr, c as isize + dc);
+        if nr < 0 || nc < 0 || nr > rows as isize || nc > cols as isize {
+            return None;
+        }
+
+        let (a, b) = match (dr, dc) {
+            (0, _) => {
+                let col = c.min(nc as usize) as isize;
+                ((r as isize - 1, col), (r as isize, col))
+            }
+            _ => {
+                let row = r.min(nr as usize) as isize;
+                ((row, c as isize - 1), (row, c as isize))
+            }
+        };
+
+        match is_inside(&inside, a.0, a.1) != is_inside(&inside, b.0, b.1) {
+            true => Some((nr as usize, nc as usize)),
+            false => None,
+        }
+    };
+
+    // The top edge of the first inside cell in reading order is always on
+    // the outline.
+    let start = *cells.iter().min().unwrap();
+    let mut path = vec![start];
+    let mut previous = None;
+    let mut current = start;
+
+    loop {
+        let next = RING
+            .iter()
+            .step_by(2)
+            .filter_map(|&step| edge(current, step))
+            .find(|&next| Some(next) != previous)
+            .expect("Outline is not a loop");
+
+        if next == start {
+            break;
+        }
+
+        path.push(next);
+        previous = Some(current);
+        current = next;
+    }
+
+    return path;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::{simple_loop, Rng};
+
+    #[test]
+    fn test_rng_is_seeded() {
+        let (mut first, mut second) = (Rng::new(7), Rng::new(7));
+        let a: Vec<u64> = (0..5).map(|_| first.range(10..20)).collect();
+        let b: Vec<u64> = (0..5).map(|_| second.range(10..20)).collect();
+
+        assert_eq!(a, b);
+        assert!(a.iter().all(|v| (10..20).contains(v)));
+        assert_ne!(Rng::new(7).next_u64(), Rng::new(8).next_u64());
+    }
+
+    #[test]
+    fn test_simple_loop() {
+        for seed in 0..20 {
+            let path = simple_loop(&mut Rng::new(seed), 12, 9);
+
+            let unique: HashSet<_> = path.iter().collect();
+            assert_eq!(unique.len(), path.len());
+            assert!(path.len() >= 4);
+
+            for (i, &(r, c)) in path.iter().enumerate() {
+                let (nr, nc) = path[(i + 1) % path.len()];
+                assert!(r <= 12 && c <= 9);
+                assert_eq!(r.abs_diff(nr) + c.abs_diff(nc), 1);
+            }
+        }
+    }
+}
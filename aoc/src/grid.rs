@@ -0,0 +1,357 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Index, IndexMut},
+};
+
+use crate::{Direction, Point, Vector};
+
+/// A `(row, column)` position in a [`Grid`], counted from the top left.
+pub type Position = (usize, usize);
+
+/// Offsets to all eight neighbours, clockwise from up.
+const SURROUNDING: [Vector; 8] = [
+    Vector::new(-1, 0),
+    Vector::new(-1, 1),
+    Vector::new(0, 1),
+    Vector::new(1, 1),
+    Vector::new(1, 0),
+    Vector::new(1, -1),
+    Vector::new(0, -1),
+    Vector::new(-1, -1),
+];
+
+/// A rectangular grid of cells, stored row by row.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Vec<T>,
+    width: usize,
+    height: usize,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid of `cells` in row major order.
+    ///
+    /// # Panics
+    ///
+    /// If there are not exactly `width * height` cells.
+    pub fn new(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert_eq!(
+            cells.len(),
+            width * height,
+            "a {}x{} grid needs {} cells",
+            width,
+            height,
+            width * height
+        );
+
+        return Self {
+            cells,
+            width,
+            height,
+        };
+    }
+
+    /// A grid with a cell for every character of each line of `input`,
+    /// mapped by `cell`.
+    ///
+    /// # Panics
+    ///
+    /// If the lines are not all the same length.
+    pub fn parse(input: &str, mut cell: impl FnMut(char) -> T) -> Self {
+        let mut cells = Vec::with_capacity(input.len());
+        let mut width = None;
+        let mut height = 0;
+
+        for (row, line) in input.lines().enumerate() {
+            let before = cells.len();
+            cells.extend(line.chars().map(&mut cell));
+
+            let length = cells.len() - before;
+            match width {
+                None => width = Some(length),
+                Some(width) => assert_eq!(
+                    length, width,
+                    "row {} is {} cells long, expected {}",
+                    row, length, width
+                ),
+            }
+
+            height += 1;
+        }
+
+        return Self::new(width.unwrap_or(0), height, cells);
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, (row, col): Position) -> bool {
+        row < self.height && col < self.width
+    }
+
+    pub fn get(&self, position: Position) -> Option<&T> {
+        match self.contains(position) {
+            true => self.cells.get(position.0 * self.width + position.1),
+            false => None,
+        }
+    }
+
+    pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+        match self.contains(position) {
+            true => self.cells.get_mut(position.0 * self.width + position.1),
+            false => None,
+        }
+    }
+
+    /// The position `vector` away from `position`, if it is still in the
+    /// grid.
+    pub fn offset(&self, position: Position, vector: Vector) -> Option<Position> {
+        let next = (Point::from(position) + vector).position()?;
+
+        match self.contains(next) {
+            true => Some(next),
+            false => None,
+        }
+    }
+
+    /// The position one step from `position` in `direction`, if it is still
+    /// in the grid.
+    pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+        self.offset(position, direction.vector())
+    }
+
+    /// The up to four orthogonal neighbours of `position`, clockwise from up.
+    pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        Direction::ALL
+            .into_iter()
+            .filter_map(move |direction| self.step(position, direction))
+    }
+
+    /// The up to eight neighbours of `position`, diagonals included,
+    /// clockwise from up.
+    pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        SURROUNDING
+            .iter()
+            .filter_map(move |&delta| self.offset(position, delta))
+    }
+
+    /// The cells of row `row`, left to right.
+    ///
+    /// # Panics
+    ///
+    /// If `row` is outside the grid.
+    pub fn row(&self, row: usize) -> &[T] {
+        assert!(row < self.height, "row {} is outside the grid", row);
+        &self.cells[row * self.width..(row + 1) * self.width]
+    }
+
+    /// The cells of column `col`, top to bottom.
+    ///
+    /// # Panics
+    ///
+    /// If `col` is outside the grid.
+    pub fn column(&self, col: usize) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator {
+        assert!(col < self.width, "column {} is outside the grid", col);
+        self.cells[col..].iter().step_by(self.width)
+    }
+
+    /// Every row, top to bottom.
+    pub fn rows(&self) -> impl DoubleEndedIterator<Item = &[T]> + ExactSizeIterator {
+        (0..self.height).map(|row| self.row(row))
+    }
+
+    /// Every column, left to right.
+    pub fn columns(
+        &self,
+    ) -> impl DoubleEndedIterator<Item = impl DoubleEndedIterator<Item = &T>> + ExactSizeIterator
+    {
+        (0..self.width).map(|col| self.column(col))
+    }
+
+    /// Every cell with its position, in row major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(index, cell)| ((index / self.width, index % self.width), cell))
+    }
+
+    /// The first position holding `value`, in row major order.
+    pub fn find(&self, value: &T) -> Option<Position>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// Every position holding `value`, in row major order.
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = Position> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    /// A grid of the same shape with every cell mapped by `f`.
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid::new(self.width, self.height, self.cells.iter().map(f).collect())
+    }
+
+    /// The grid mirrored along its main diagonal, so rows become columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flatten().cloned().collect();
+        return Self::new(self.height, self.width, cells);
+    }
+
+    /// The grid turned a quarter clockwise, so the left column becomes the
+    /// top row.
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().flat_map(Iterator::rev).cloned().collect();
+        return Self::new(self.height, self.width, cells);
+    }
+
+    /// The grid turned a quarter counter clockwise, so the right column
+    /// becomes the top row.
+    pub fn rotate_counter_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let cells = self.columns().rev().flatten().cloned().collect();
+        return Self::new(self.height, self.width, cells);
+    }
+}
+
+impl From<&str> for Grid<char> {
+    fn from(value: &str) -> Self {
+        Self::parse(value, |c| c)
+    }
+}
+
+impl<T> Index<Position> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: Position) -> &T {
+        self.get(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+    fn index_mut(&mut self, position: Position) -> &mut T {
+        self.get_mut(position)
+            .unwrap_or_else(|| panic!("{:?} is outside the grid", position))
+    }
+}
+
+/// One line per row, without a trailing newline.
+impl<T: Display> Display for Grid<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        for (index, row) in self.rows().enumerate() {
+            if index > 0 {
+                writeln!(f)?;
+            }
+
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+        }
+
+        return Ok(());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Grid;
+    use crate::{Direction, Vector};
+
+    const INPUT: &str = "abc\ndef\n";
+
+    #[test]
+    fn test_parse_and_get() {
+        let grid = Grid::from(INPUT);
+
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get((1, 2)), Some(&'f'));
+        assert_eq!(grid.get((2, 0)), None);
+        assert_eq!(grid.get((0, 3)), None);
+        assert_eq!(grid[(0, 1)], 'b');
+
+        let digits = Grid::parse("12\n34", |c| c.to_digit(10).unwrap());
+        assert_eq!(digits.row(1), &[3, 4]);
+        assert_eq!(Grid::from("").height(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "row 1 is 2 cells long, expected 3")]
+    fn test_parse_ragged() {
+        let _ = Grid::from("abc\nde");
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let grid = Grid::from(INPUT);
+
+        let corner: Vec<_> = grid.neighbours4((0, 0)).collect();
+        assert_eq!(corner, vec![(0, 1), (1, 0)]);
+
+        let edge: Vec<_> = grid.neighbours8((1, 1)).collect();
+        assert_eq!(edge, vec![(0, 1), (0, 2), (1, 2), (1, 0), (0, 0)]);
+
+        assert_eq!(grid.offset((1, 2), Vector::new(-1, -2)), Some((0, 0)));
+        assert_eq!(grid.step((1, 2), Direction::East), None);
+        assert_eq!(grid.step((1, 2), Direction::North), Some((0, 2)));
+    }
+
+    #[test]
+    fn test_rows_and_columns() {
+        let grid = Grid::from(INPUT);
+
+        let columns: Vec<String> = grid.columns().map(|c| c.collect()).collect();
+        assert_eq!(columns, vec!["ad", "be", "cf"]);
+
+        let rows: Vec<String> = grid.rows().rev().map(|r| r.iter().collect()).collect();
+        assert_eq!(rows, vec!["def", "abc"]);
+    }
+
+    #[test]
+    fn test_rotate_and_transpose() {
+        let grid = Grid::from(INPUT);
+
+        assert_eq!(grid.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(grid.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(grid.rotate_counter_clockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(
+            grid.rotate_clockwise().rotate_counter_clockwise(),
+            grid.clone()
+        );
+    }
+
+    #[test]
+    fn test_find() {
+        let mut grid = Grid::from("#.#\n..#");
+        grid[(1, 0)] = '#';
+
+        assert_eq!(grid.find(&'.'), Some((0, 1)));
+        assert_eq!(grid.find(&'x'), None);
+
+        let rocks: Vec<_> = grid.find_all(&'#').collect();
+        assert_eq!(rocks, vec![(0, 0), (0, 2), (1, 0), (1, 2)]);
+        assert_eq!(grid.map(|&c| c == '#').find(&false), Some((0, 1)));
+    }
+}
@@ -0,0 +1,326 @@
+use std::{
+    collections::BTreeMap,
+    fs::{self, OpenOptions},
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::Command,
+    sync::OnceLock,
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{runner::DayResult, Day};
+
+const HEADERS: [&str; 9] = [
+    "When", "Commit", "Parse", "Part 1", "Part 2", "Solve", "Total", "Change", "",
+];
+
+const STAGES: [&str; 4] = ["parse", "part1", "part2", "solve"];
+
+/// Columns holding times and changes, which are right-aligned.
+const TIME_COLUMNS: [usize; 6] = [2, 3, 4, 5, 6, 7];
+
+pub fn default_history_path(day: Day) -> PathBuf {
+    day.dir().join("history.jsonl")
+}
+
+/// Whether this binary was built with optimisations, as timings from debug
+/// and release builds are not worth comparing.
+pub fn profile() -> &'static str {
+    match cfg!(debug_assertions) {
+        true => "debug",
+        false => "release",
+    }
+}
+
+/// Short hash of the checked out commit, ending in `+` when tracked files
+/// have uncommitted changes, or `None` outside a git repository.
+pub fn current_commit() -> Option<String> {
+    static COMMIT: OnceLock<Option<String>> = OnceLock::new();
+
+    let commit = COMMIT.get_or_init(|| {
+        let output = Command::new("git")
+            .args(["rev-parse", "--short", "HEAD"])
+            .output()
+            .ok()
+            .filter(|output| output.status.success())?;
+        let hash = String::from_utf8(output.stdout).ok()?.trim().to_string();
+
+        let dirty = Command::new("git")
+            .args(["status", "--porcelain", "--untracked-files=no"])
+            .output()
+            .is_ok_and(|output| !output.stdout.is_empty());
+
+        return Some(match dirty {
+            true => format!("{}+", hash),
+            false => hash,
+        });
+    });
+
+    return commit.clone();
+}
+
+/// One timed run of an input, kept as a line of `./dayN/history.jsonl`.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub commit: Option<String>,
+    pub profile: String,
+    pub input: String,
+    /// Nanoseconds per stage, and in total. Benchmarked runs keep the medians.
+    pub timings_ns: BTreeMap<String, u128>,
+}
+
+impl Entry {
+    pub fn new(result: &DayResult) -> Self {
+        let timings_ns = match &result.benchmark {
+            Some(bench) => bench
+                .stages
+                .iter()
+                .map(|(stage, stats)| (stage.key().to_string(), stats.median.as_nanos()))
+                .chain([("total".to_string(), bench.total.median.as_nanos())])
+                .collect(),
+            None => result
+                .timings
+                .iter()
+                .map(|(stage, time)| (stage.key().to_string(), time.as_nanos()))
+                .chain([("total".to_string(), result.total_time().as_nanos())])
+                .collect(),
+        };
+
+        return Self {
+            timestamp: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .expect("Clock is before the Unix epoch")
+                .as_secs(),
+            commit: current_commit(),
+            profile: profile().to_string(),
+            input: result.input.clone(),
+            timings_ns,
+        };
+    }
+
+    fn time(&self, stage: &str) -> Option<Duration> {
+        self.timings_ns
+            .get(stage)
+            .map(|&ns| Duration::from_nanos(ns as u64))
+    }
+
+    pub fn total(&self) -> Duration {
+        self.time("total").unwrap_or_default()
+    }
+}
+
+/// `YYYY-MM-DD HH:MM` in UTC.
+fn date(timestamp: u64) -> String {
+    let (days, secs) = (timestamp / 86400, timestamp % 86400);
+
+    // Howard Hinnant's days-to-civil conversion, with years starting in March.
+    let z = days as i64 + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    format!(
+        "{:04}-{:02}-{:02} {:02}:{:02}",
+        year,
+        month,
+        day,
+        secs / 3600,
+        secs % 3600 / 60
+    )
+}
+
+fn change(new: Duration, old: Duration) -> f64 {
+    (new.as_secs_f64() - old.as_secs_f64()) / old.as_secs_f64().max(f64::MIN_POSITIVE) * 100.0
+}
+
+/// Every recorded run of a day, oldest first.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    entries: Vec<Entry>,
+}
+
+impl History {
+    /// Loads the history file, treating a missing file as an empty history.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(e),
+        };
+
+        let entries = contents
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(serde_json::from_str)
+            .collect::<Result<_, _>>()
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+
+        return Ok(Self { entries });
+    }
+
+    /// Adds a run to the end of the history file, creating it if need be.
+    pub fn append(path: &Path, entry: &Entry) -> io::Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        let line = serde_json::to_string(entry).map_err(io::Error::from)?;
+
+        writeln!(file, "{}", line)
+    }
+
+    pub fn entries(&self) -> &[Entry] {
+        &self.entries
+    }
+
+    /// One table per input and build profile, showing each run's change in
+    /// total time from the run before. Runs more than `threshold` percent
+    /// slower than the best are flagged.
+    pub fn render(&self, threshold: f64) -> String {
+        let mut groups: Vec<((&str, &str), Vec<&Entry>)> = Vec::new();
+        for entry in &self.entries {
+            let key = (entry.input.as_str(), entry.profile.as_str());
+            match groups.iter_mut().find(|(k, _)| *k == key) {
+                Some((_, entries)) => entries.push(entry),
+                None => groups.push((key, vec![entry])),
+            }
+        }
+
+        let mut tables = Vec::new();
+
+        for ((input, profile), entries) in groups {
+            let best = entries.iter().map(|e| e.total()).min().unwrap_or_default();
+
+            let mut rows = vec![HEADERS.map(String::from)];
+            let mut slower = 0;
+            for (i, entry) in entries.iter().enumerate() {
+                let mut row: [String; 9] = Default::default();
+                row[0] = date(entry.timestamp);
+                row[1] = entry.commit.clone().unwrap_or_else(|| "-".to_string());
+                for (cell, stage) in row[2..6].iter_mut().zip(STAGES) {
+                    *cell = entry
+                        .time(stage)
+                        .map_or("-".to_string(), |time| format!("{:.2?}", time));
+                }
+                row[6] = format!("{:.2?}", entry.total());
+                row[7] = match i {
+                    0 => "-".to_string(),
+                    _ => format!("{:+.0}%", change(entry.total(), entries[i - 1].total())),
+                };
+
+                let over_best = change(entry.total(), best);
+                row[8] = match (entry.total() == best, over_best > threshold) {
+                    (true, _) => "best".to_string(),
+                    (false, true) => {
+                        slower += 1;
+                        format!("SLOWER, {:+.0}% on best", over_best)
+                    }
+                    (false, false) => String::new(),
+                };
+
+                rows.push(row);
+            }
+
+            let mut widths = [0; 9];
+            for row in &rows {
+                for (width, cell) in widths.iter_mut().zip(row) {
+                    *width = (*width).max(cell.chars().count());
+                }
+            }
+
+            let mut lines = vec![format!("{} ({})", input, profile)];
+            lines.extend(rows.iter().map(|row| {
+                row.iter()
+                    .enumerate()
+                    .map(|(i, cell)| match TIME_COLUMNS.contains(&i) {
+                        true => format!("{:>1$}", cell, widths[i]),
+                        false => format!("{:<1$}", cell, widths[i]),
+                    })
+                    .collect::<Vec<String>>()
+                    .join("  ")
+                    .trim_end()
+                    .to_string()
+            }));
+            lines.push(format!(
+                "{} runs, best {:.2?}, {} significantly slower",
+                entries.len(),
+                best,
+                slower
+            ));
+
+            tables.push(lines.join("\n"));
+        }
+
+        return tables.join("\n\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs};
+
+    use super::{date, Entry, History};
+
+    fn entry(timestamp: u64, commit: &str, total_us: u128) -> Entry {
+        Entry {
+            timestamp,
+            commit: Some(commit.to_string()),
+            profile: "release".to_string(),
+            input: "input".to_string(),
+            timings_ns: BTreeMap::from([
+                ("parse".to_string(), 1000),
+                ("total".to_string(), total_us * 1000),
+            ]),
+        }
+    }
+
+    #[test]
+    fn test_date() {
+        assert_eq!(date(0), "1970-01-01 00:00");
+        assert_eq!(date(1_701_388_800 + 3661), "2023-12-01 01:01");
+        assert_eq!(date(951_782_400), "2000-02-29 00:00");
+    }
+
+    #[test]
+    fn test_append_and_load() {
+        let path = std::env::temp_dir().join(format!("aoc-history-{}.jsonl", std::process::id()));
+        let _ = fs::remove_file(&path);
+
+        assert!(History::load(&path).unwrap().entries().is_empty());
+
+        let entries = [entry(0, "abc1234", 100), entry(60, "abc1234+", 90)];
+        for entry in &entries {
+            History::append(&path, entry).unwrap();
+        }
+
+        assert_eq!(History::load(&path).unwrap().entries(), entries);
+
+        let _ = fs::remove_file(&path);
+    }
+
+    #[test]
+    fn test_render_flags_slow_runs() {
+        let history = History {
+            entries: vec![
+                entry(0, "aaaaaaa", 100),
+                entry(60, "bbbbbbb", 80),
+                entry(120, "ccccccc", 120),
+            ],
+        };
+
+        let rendered = history.render(30.0);
+        let lines: Vec<&str> = rendered.lines().collect();
+
+        assert_eq!(lines[0], "input (release)");
+        assert!(lines[2].ends_with("100.00µs       -"));
+        assert!(lines[3].ends_with("-20%  best"));
+        assert!(lines[4].ends_with("+50%  SLOWER, +50% on best"));
+        assert_eq!(lines[5], "3 runs, best 80.00µs, 1 significantly slower");
+    }
+}
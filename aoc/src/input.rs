@@ -0,0 +1,367 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs,
+    io::{self, Read},
+    path::{Component, Path, PathBuf},
+};
+
+use crate::{
+    fetch::{cached_input_path, default_cache_dir},
+    Day,
+};
+
+/// `src/input.txt` in the day's crate, or the input fetched into the cache
+/// when there is no local one.
+pub fn default_input_path(day: Day) -> PathBuf {
+    input_path_in(day, default_cache_dir())
+}
+
+/// [`default_input_path`], with inputs fetched into `cache_dir`.
+pub(crate) fn input_path_in(day: Day, cache_dir: Option<PathBuf>) -> PathBuf {
+    let local = day.dir().join("src").join("input.txt");
+
+    match cache_dir {
+        Some(cache_dir) if !local.exists() => {
+            let cached = cached_input_path(&cache_dir, day.year, day.day);
+            match cached.exists() {
+                true => cached,
+                false => local,
+            }
+        }
+        _ => local,
+    }
+}
+
+pub fn examples_dir(day: Day) -> PathBuf {
+    day.dir().join("examples")
+}
+
+/// Where a puzzle input is read from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Stdin,
+    File(PathBuf),
+    /// One of the puzzle examples in `./dayN/examples`.
+    Example(PathBuf),
+    Inline(String),
+}
+
+/// A puzzle input, named after its source so known answers can be kept per input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Input {
+    pub name: String,
+    pub contents: String,
+    /// Whether this is a puzzle example, checked against the example answers.
+    pub example: bool,
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    pub source: String,
+    pub error: io::Error,
+}
+
+impl Display for InputError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Could not read input {}: {}", self.source, self.error)
+    }
+}
+
+impl std::error::Error for InputError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+impl Source {
+    pub fn name(&self) -> String {
+        match self {
+            Source::Stdin => "stdin".to_string(),
+            Source::File(path) | Source::Example(path) => path
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or("input".to_string()),
+            Source::Inline(_) => "inline".to_string(),
+        }
+    }
+
+    pub fn read(&self) -> Result<Input, InputError> {
+        let contents = match self {
+            Source::Stdin => {
+                let mut contents = String::new();
+                io::stdin().read_to_string(&mut contents).map(|_| contents)
+            }
+            Source::File(path) | Source::Example(path) => fs::read_to_string(path),
+            Source::Inline(contents) => Ok(contents.clone()),
+        };
+
+        return contents
+            .map(|contents| Input {
+                name: self.name(),
+                contents,
+                example: matches!(self, Source::Example(_)),
+            })
+            .map_err(|error| InputError {
+                source: self.to_string(),
+                error,
+            });
+    }
+}
+
+impl Display for Source {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Source::Stdin => write!(f, "from stdin"),
+            Source::File(path) | Source::Example(path) => write!(f, "{}", path.display()),
+            Source::Inline(_) => write!(f, "given inline"),
+        }
+    }
+}
+
+/// The last `depth` components of `path`, without its extension, or `None`
+/// when it has fewer.
+fn trailing_name(path: &Path, depth: usize) -> Option<String> {
+    let components: Vec<String> = path
+        .with_extension("")
+        .components()
+        .filter_map(|c| match c {
+            Component::Normal(c) => Some(c.to_string_lossy().into_owned()),
+            _ => None,
+        })
+        .collect();
+
+    let start = components.len().checked_sub(depth)?;
+    return Some(components[start..].join("/"));
+}
+
+/// Names each source for its known answers. Files that share a name, like
+/// `alice/input.txt` and `bob/input.txt`, take on as many parent directories
+/// as it takes to tell them apart, so they don't share answers either.
+pub fn names(sources: &[Source]) -> Vec<String> {
+    let mut names: Vec<String> = sources.iter().map(Source::name).collect();
+
+    for depth in 2.. {
+        let clashes: Vec<bool> = names
+            .iter()
+            .map(|name| names.iter().filter(|other| *other == name).count() > 1)
+            .collect();
+        let mut renamed = false;
+
+        for i in 0..sources.len() {
+            let path = match &sources[i] {
+                Source::File(path) | Source::Example(path) if clashes[i] => path,
+                _ => continue,
+            };
+
+            if let Some(name) = trailing_name(path, depth) {
+                renamed |= name != names[i];
+                names[i] = name;
+            }
+        }
+
+        // Nothing left to tell apart, or the same file given twice.
+        if !renamed {
+            break;
+        }
+    }
+
+    return names;
+}
+
+fn directory_inputs(dir: &Path) -> Result<Vec<PathBuf>, InputError> {
+    let to_error = |error| InputError {
+        source: dir.display().to_string(),
+        error,
+    };
+
+    let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+        .map_err(to_error)?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && path.extension().is_some_and(|ext| ext == "txt"))
+        .collect();
+
+    if paths.is_empty() {
+        return Err(to_error(io::Error::new(
+            io::ErrorKind::NotFound,
+            "directory contains no *.txt files",
+        )));
+    }
+
+    paths.sort();
+
+    return Ok(paths);
+}
+
+/// Gives every input the same shape: `\n` line endings, no trailing
+/// whitespace on any line, no trailing blank lines and a single final newline.
+pub fn normalise(contents: &str) -> String {
+    let mut normalised = contents
+        .lines()
+        .map(str::trim_end)
+        .collect::<Vec<&str>>()
+        .join("\n")
+        .trim_end()
+        .to_string();
+
+    if !normalised.is_empty() {
+        normalised.push('\n');
+    }
+
+    return normalised;
+}
+
+/// Expands command line input arguments into sources: `-` reads stdin, a
+/// directory yields each of its `*.txt` files in name order, and anything
+/// else is a file. With no arguments the day's default input is used.
+pub fn sources(day: Day, args: &[PathBuf]) -> Result<Vec<Source>, InputError> {
+    if args.is_empty() {
+        return Ok(vec![Source::File(default_input_path(day))]);
+    }
+
+    let mut sources = vec![];
+
+    for arg in args {
+        match arg {
+            _ if arg.as_os_str() == "-" => sources.push(Source::Stdin),
+            _ if arg.is_dir() => {
+                sources.extend(directory_inputs(arg)?.into_iter().map(Source::File))
+            }
+            _ => sources.push(Source::File(arg.clone())),
+        }
+    }
+
+    return Ok(sources);
+}
+
+/// The named example from `./dayN/examples`, or all of them when no name is given.
+pub fn example_sources(day: Day, name: Option<&str>) -> Result<Vec<Source>, InputError> {
+    example_sources_in(Path::new(""), day, name)
+}
+
+/// [`example_sources`] for the workspace at `root`, rather than the current
+/// directory.
+pub fn example_sources_in(
+    root: &Path,
+    day: Day,
+    name: Option<&str>,
+) -> Result<Vec<Source>, InputError> {
+    let dir = root.join(examples_dir(day));
+
+    let paths = match name {
+        Some(name) => vec![dir.join(format!("{}.txt", name))],
+        None => directory_inputs(&dir)?,
+    };
+
+    return Ok(paths.into_iter().map(Source::Example).collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, path::PathBuf};
+
+    use super::{
+        default_input_path, example_sources, examples_dir, names, normalise, sources, Source,
+    };
+    use crate::Day;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("aoc-input-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        return dir;
+    }
+
+    #[test]
+    fn test_normalise() {
+        assert_eq!(normalise("a \r\nb\t\r\n\r\nc\r\n\r\n"), "a\nb\n\nc\n");
+        assert_eq!(normalise("1,2,3"), "1,2,3\n");
+        assert_eq!(normalise("  x\n  \n"), "  x\n");
+        assert_eq!(normalise("\n\n"), "");
+    }
+
+    #[test]
+    fn test_default_source() {
+        let result = sources(Day::new(2023, 5), &[]).unwrap();
+        assert_eq!(
+            result,
+            vec![Source::File(default_input_path(Day::new(2023, 5)))]
+        );
+        assert_eq!(result[0].name(), "input");
+    }
+
+    #[test]
+    fn test_directory_sources() {
+        let dir = temp_dir("directory");
+        fs::write(dir.join("bob.txt"), "2").unwrap();
+        fs::write(dir.join("alice.txt"), "1").unwrap();
+        fs::write(dir.join("notes.md"), "ignored").unwrap();
+
+        let result = sources(Day::new(2023, 1), &[PathBuf::from("-"), dir.clone()]).unwrap();
+        let names: Vec<String> = result.iter().map(|s| s.name()).collect();
+
+        assert_eq!(names, vec!["stdin", "alice", "bob"]);
+        assert_eq!(result[1].read().unwrap().contents, "1");
+
+        let empty = temp_dir("empty");
+        assert!(sources(Day::new(2023, 1), &[empty]).is_err());
+    }
+
+    #[test]
+    fn test_names() {
+        let sources = [
+            Source::File(PathBuf::from("accounts/alice/input.txt")),
+            Source::File(PathBuf::from("./accounts/bob/input.txt")),
+            Source::File(PathBuf::from("big.txt")),
+            Source::Stdin,
+            Source::File(PathBuf::from("x/2022/input.txt")),
+            Source::File(PathBuf::from("y/2022/input.txt")),
+        ];
+        assert_eq!(
+            names(&sources),
+            [
+                "alice/input",
+                "bob/input",
+                "big",
+                "stdin",
+                "x/2022/input",
+                "y/2022/input"
+            ]
+        );
+
+        let twice = [
+            Source::File(PathBuf::from("input.txt")),
+            Source::File(PathBuf::from("input.txt")),
+        ];
+        assert_eq!(names(&twice), ["input", "input"]);
+    }
+
+    #[test]
+    fn test_missing_input_error() {
+        let path = PathBuf::from("./day99/src/input.txt");
+        let error = Source::File(path).read().unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("Could not read input ./day99/src/input.txt: "));
+    }
+
+    #[test]
+    fn test_example_sources() {
+        let result = example_sources(Day::new(2023, 3), Some("larger")).unwrap();
+        assert_eq!(
+            result,
+            vec![Source::Example(
+                examples_dir(Day::new(2023, 3)).join("larger.txt")
+            )]
+        );
+        assert_eq!(result[0].name(), "larger");
+    }
+
+    #[test]
+    fn test_inline_source() {
+        let input = Source::Inline("1 2 3".to_string()).read().unwrap();
+
+        assert_eq!(input.name, "inline");
+        assert_eq!(input.contents, "1 2 3");
+    }
+}
@@ -1,34 +1,62 @@
-use std::{env::args, fs, time::Instant};
-
-pub type Result = (Option<u64>, Option<u64>);
-
-pub fn run(day: u8, runner: fn(&str) -> Result) {
-    println!("Advent of Code, Day {}!", day);
-
-    let file_path = args().nth(1)
-        .unwrap_or(format!("./day{}/src/input.txt", day));
-
-    let input = fs::read_to_string(file_path).expect("Should have been able to read the file");
-
-    let timer = Instant::now();
-
-    let (part_1, part_2) = runner(input.as_str());
-
-    let time_taken = timer.elapsed();
-
-    let part_1 = match part_1 {
-        Some(v) => format!("{}", v),
-        None => "No value!".to_string(),
-    };
-
-    println!("Day {day} Result, Part 1: {}", part_1);
-
-    let part_2 = match part_2 {
-        Some(v) => format!("{}", v),
-        None => "No value!".to_string(),
+mod alloc;
+mod answer;
+mod answers;
+mod bench;
+mod cancel;
+mod cli;
+mod dashboard;
+mod day;
+mod fetch;
+pub mod gen;
+mod grid;
+mod history;
+mod input;
+pub mod log;
+mod output;
+mod point;
+mod registry;
+mod runner;
+mod scaffold;
+pub mod search;
+mod solution;
+mod submit;
+mod summary;
+mod watch;
+
+pub use alloc::{AllocStats, CountingAllocator};
+pub use answer::Answer;
+pub use answers::{AnswersError, KnownAnswers, Status};
+pub use bench::{Benchmark, Stats};
+pub use cancel::CancelToken;
+pub use cli::{day_main, main};
+pub use day::{Day, ROOT_YEAR};
+pub use fetch::{FetchError, Fetched, Fetcher};
+pub use grid::{Grid, Position};
+pub use history::{Entry, History};
+pub use input::{
+    default_input_path, example_sources, example_sources_in, normalise, Input, InputError, Source,
+};
+pub use output::Format;
+pub use point::{Direction, ParseDirectionError, Point, Vector};
+pub use registry::{Register, Registry};
+pub use runner::{run, DayResult, Options, PartResult};
+pub use scaffold::{new_day, ScaffoldError};
+pub use solution::{Report, Solution, Solver, Stage};
+pub use submit::{Hint, Outcome, SubmitError, Submitter, Verdict};
+pub use summary::Summary;
+
+pub type Result = (Option<Answer>, Option<Answer>);
+
+/// Includes `examples/<name>.txt` from the calling crate, so unit tests share
+/// the example inputs used by `aoc run N --example`.
+#[macro_export]
+macro_rules! example {
+    ($name:literal) => {
+        include_str!(concat!(
+            env!("CARGO_MANIFEST_DIR"),
+            "/examples/",
+            $name,
+            ".txt"
+        ))
     };
-
-    println!("Day {day} Result, Part 2: {}", part_2);
-
-    println!("Time Taken: {:?}", time_taken);
-}
\ No newline at end of file
+}
@@ -0,0 +1,301 @@
+use std::{
+    cell::Cell,
+    collections::BTreeMap,
+    fmt::{self, Display, Formatter},
+    str::FromStr,
+    sync::{
+        atomic::{AtomicU8, Ordering},
+        mpsc::Sender,
+        Mutex, RwLock,
+    },
+};
+
+use crate::{day::parse_day_number, Day};
+
+/// How much a solver logs, from least to most verbose.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Level {
+    Error = 1,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl Level {
+    /// The level for `-v` given `count` times, on top of the default `warn`.
+    pub fn from_verbosity(count: u8) -> Self {
+        match count {
+            0 => Level::Warn,
+            1 => Level::Info,
+            2 => Level::Debug,
+            _ => Level::Trace,
+        }
+    }
+}
+
+impl Display for Level {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Level::Error => "ERROR",
+            Level::Warn => "WARN",
+            Level::Info => "INFO",
+            Level::Debug => "DEBUG",
+            Level::Trace => "TRACE",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+impl FromStr for Level {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "error" => Ok(Level::Error),
+            "warn" => Ok(Level::Warn),
+            "info" => Ok(Level::Info),
+            "debug" => Ok(Level::Debug),
+            "trace" => Ok(Level::Trace),
+            _ => Err(format!("unknown log level `{}`", s)),
+        }
+    }
+}
+
+/// Which messages are printed: a default level, overridden for single days.
+/// Parsed from specs such as `debug`, `2022/17=trace` or `info,12=off,17=trace`,
+/// where a day without a year is placed in one by [`Filter::in_year`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// `None` turns logging off.
+    default: Option<Level>,
+    days: BTreeMap<Day, Option<Level>>,
+    undated: BTreeMap<u8, Option<Level>>,
+}
+
+impl Filter {
+    pub const fn new(default: Option<Level>) -> Self {
+        return Self {
+            default,
+            days: BTreeMap::new(),
+            undated: BTreeMap::new(),
+        };
+    }
+
+    /// Applies the days given without a year to those days of `year`, unless
+    /// the same day was also given with its year.
+    pub fn in_year(mut self, year: u16) -> Self {
+        for (day, level) in std::mem::take(&mut self.undated) {
+            self.days.entry(Day::new(year, day)).or_insert(level);
+        }
+
+        return self;
+    }
+
+    pub fn level(&self, day: Option<Day>) -> Option<Level> {
+        day.and_then(|day| self.days.get(&day).copied())
+            .unwrap_or(self.default)
+    }
+
+    fn max_level(&self) -> Option<Level> {
+        self.days
+            .values()
+            .chain(self.undated.values())
+            .copied()
+            .chain([self.default])
+            .max()
+            .flatten()
+    }
+}
+
+impl Default for Filter {
+    fn default() -> Self {
+        return Self::new(Some(Level::Warn));
+    }
+}
+
+fn parse_level(s: &str) -> Result<Option<Level>, String> {
+    match s {
+        "off" => Ok(None),
+        _ => s.parse().map(Some),
+    }
+}
+
+impl FromStr for Filter {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut filter = Self::default();
+
+        for directive in s.split(',').map(str::trim).filter(|d| !d.is_empty()) {
+            match directive.split_once('=') {
+                Some((day, level)) if day.contains('/') => {
+                    filter.days.insert(day.parse()?, parse_level(level)?);
+                }
+                Some((day, level)) => match parse_day_number(day.trim_start_matches("day")) {
+                    Some(number) => {
+                        filter.undated.insert(number?, parse_level(level)?);
+                    }
+                    None => {
+                        return Err(format!(
+                            "expected a day like `17` or `2023/17`, got `{}`",
+                            day
+                        ))
+                    }
+                },
+                None => filter.default = parse_level(directive)?,
+            }
+        }
+
+        return Ok(filter);
+    }
+}
+
+/// The most verbose level any day may log at, so that disabled messages
+/// cost a single atomic load.
+static MAX_LEVEL: AtomicU8 = AtomicU8::new(Level::Warn as u8);
+static FILTER: RwLock<Filter> = RwLock::new(Filter::new(Some(Level::Warn)));
+/// Where messages go instead of stderr, while something else owns the terminal.
+static SINK: Mutex<Option<Sender<String>>> = Mutex::new(None);
+
+thread_local! {
+    static DAY: Cell<Option<Day>> = const { Cell::new(None) };
+    static MUTED: Cell<bool> = const { Cell::new(false) };
+}
+
+pub fn set_filter(filter: Filter) {
+    let max_level = filter.max_level().map_or(0, |level| level as u8);
+    *FILTER.write().unwrap() = filter;
+    MAX_LEVEL.store(max_level, Ordering::Relaxed);
+}
+
+/// Sends every message to `sink` rather than stderr, until called with `None`.
+pub(crate) fn capture(sink: Option<Sender<String>>) {
+    *SINK.lock().unwrap() = sink;
+}
+
+/// Runs `f` with its messages attributed to, and filtered by, `day`.
+pub(crate) fn with_day<T>(day: Day, f: impl FnOnce() -> T) -> T {
+    let previous = DAY.with(|d| d.replace(Some(day)));
+    let result = f();
+    DAY.with(|d| d.set(previous));
+    return result;
+}
+
+/// Runs `f` without logging, e.g. for the repeated runs of a benchmark.
+pub(crate) fn muted<T>(f: impl FnOnce() -> T) -> T {
+    let previous = MUTED.with(|m| m.replace(true));
+    let result = f();
+    MUTED.with(|m| m.set(previous));
+    return result;
+}
+
+pub fn enabled(level: Level) -> bool {
+    if level as u8 > MAX_LEVEL.load(Ordering::Relaxed) || MUTED.with(Cell::get) {
+        return false;
+    }
+
+    let day = DAY.with(Cell::get);
+    return FILTER.read().unwrap().level(day) >= Some(level);
+}
+
+#[doc(hidden)]
+pub fn write(level: Level, args: fmt::Arguments) {
+    let message = match DAY.with(Cell::get) {
+        Some(day) => format!("[{} {}] {}", day, level, args),
+        None => format!("[{}] {}", level, args),
+    };
+
+    match SINK.lock().unwrap().as_ref() {
+        Some(sink) => {
+            let _ = sink.send(message);
+        }
+        None => eprintln!("{}", message),
+    }
+}
+
+/// Logs a message to stderr, if the filter allows it for the current day.
+#[macro_export]
+macro_rules! log {
+    ($level:expr, $($arg:tt)+) => {
+        if $crate::log::enabled($level) {
+            $crate::log::write($level, format_args!($($arg)+));
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! error {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Error, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! warn {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Warn, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! info {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Info, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! debug {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Debug, $($arg)+) };
+}
+
+#[macro_export]
+macro_rules! trace {
+    ($($arg:tt)+) => { $crate::log!($crate::log::Level::Trace, $($arg)+) };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, Level};
+    use crate::Day;
+
+    #[test]
+    fn test_parse_filter() {
+        let filter: Filter = "info, 12=off, day17=trace".parse().unwrap();
+        assert_eq!(filter.max_level(), Some(Level::Trace));
+
+        let filter = filter.in_year(2023);
+        assert_eq!(filter.level(None), Some(Level::Info));
+        assert_eq!(filter.level(Some(Day::new(2023, 5))), Some(Level::Info));
+        assert_eq!(filter.level(Some(Day::new(2023, 12))), None);
+        assert_eq!(filter.level(Some(Day::new(2023, 17))), Some(Level::Trace));
+        assert_eq!(filter.level(Some(Day::new(2022, 17))), Some(Level::Info));
+
+        let default: Filter = "17=debug".parse().unwrap();
+        assert_eq!(
+            default.in_year(2023).level(Some(Day::new(2023, 1))),
+            Some(Level::Warn)
+        );
+
+        assert!("loud".parse::<Filter>().is_err());
+        assert!("x=debug".parse::<Filter>().is_err());
+        assert!("26=debug".parse::<Filter>().is_err());
+        assert!("2023/x=debug".parse::<Filter>().is_err());
+    }
+
+    #[test]
+    fn test_filter_by_year() {
+        let filter: Filter = "2022/17=trace,17=off".parse().unwrap();
+        let filter = filter.in_year(2022);
+        assert_eq!(filter.level(Some(Day::new(2022, 17))), Some(Level::Trace));
+        assert_eq!(filter.level(Some(Day::new(2023, 17))), Some(Level::Warn));
+
+        let filter: Filter = "2022/17=trace,17=off".parse().unwrap();
+        let filter = filter.in_year(2023);
+        assert_eq!(filter.level(Some(Day::new(2022, 17))), Some(Level::Trace));
+        assert_eq!(filter.level(Some(Day::new(2023, 17))), None);
+    }
+
+    #[test]
+    fn test_verbosity() {
+        assert_eq!(Level::from_verbosity(0), Level::Warn);
+        assert_eq!(Level::from_verbosity(2), Level::Debug);
+        assert_eq!(Level::from_verbosity(9), Level::Trace);
+    }
+}
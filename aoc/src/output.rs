@@ -0,0 +1,425 @@
+use std::{collections::BTreeMap, io::IsTerminal};
+
+use clap::ValueEnum;
+use serde::Serialize;
+
+use crate::{runner::DayResult, summary::Summary, AllocStats, Day, Stage, Stats};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    #[default]
+    Text,
+    /// A JSON array with one record per day and part
+    Json,
+    /// A CSV table with one row per day and part
+    Csv,
+}
+
+const CSV_STAGES: [Stage; 4] = [Stage::Parse, Stage::Part1, Stage::Part2, Stage::Solve];
+
+#[derive(Serialize)]
+struct StatsRecord {
+    min_ns: u128,
+    median_ns: u128,
+    mean_ns: u128,
+    stddev_ns: u128,
+    bytes_per_sec: f64,
+}
+
+#[derive(Serialize)]
+struct BenchRecord {
+    iterations: usize,
+    warmup: usize,
+    stages: BTreeMap<&'static str, StatsRecord>,
+}
+
+#[derive(Serialize)]
+struct AllocRecord {
+    count: usize,
+    bytes: usize,
+    peak_bytes: usize,
+}
+
+impl From<&AllocStats> for AllocRecord {
+    fn from(value: &AllocStats) -> Self {
+        return Self {
+            count: value.count,
+            bytes: value.bytes,
+            peak_bytes: value.peak,
+        };
+    }
+}
+
+#[derive(Serialize)]
+struct Record<'a> {
+    year: u16,
+    day: u8,
+    input: &'a str,
+    part: u8,
+    answer: Option<String>,
+    status: Option<String>,
+    expected: Option<&'a str>,
+    timings_ns: BTreeMap<&'static str, u128>,
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    allocations: BTreeMap<&'static str, AllocRecord>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bench: Option<BenchRecord>,
+}
+
+/// Stands in for a day's records when it could not be run at all.
+#[derive(Serialize)]
+struct ErrorRecord<'a> {
+    year: u16,
+    day: u8,
+    status: &'static str,
+    message: &'a str,
+}
+
+impl<'a> ErrorRecord<'a> {
+    fn new(day: Day, message: &'a str) -> Self {
+        return Self {
+            year: day.year,
+            day: day.day,
+            status: "error",
+            message,
+        };
+    }
+}
+
+fn stats_record(stats: &Stats, bytes: usize) -> StatsRecord {
+    StatsRecord {
+        min_ns: stats.min.as_nanos(),
+        median_ns: stats.median.as_nanos(),
+        mean_ns: stats.mean.as_nanos(),
+        stddev_ns: stats.stddev.as_nanos(),
+        bytes_per_sec: stats.throughput(bytes),
+    }
+}
+
+fn records(result: &DayResult) -> impl Iterator<Item = Record<'_>> {
+    let mut timings_ns: BTreeMap<&'static str, u128> = result
+        .timings
+        .iter()
+        .map(|(stage, time)| (stage.key(), time.as_nanos()))
+        .collect();
+    timings_ns.insert("total", result.total_time().as_nanos());
+
+    result.parts.iter().map(move |part| Record {
+        year: result.day.year,
+        day: result.day.day,
+        input: &result.input,
+        part: part.part,
+        answer: part.answer.as_ref().map(|a| a.to_string()),
+        status: part.status.map(|s| s.to_string()),
+        expected: part.expected.as_deref(),
+        timings_ns: timings_ns.clone(),
+        allocations: result
+            .allocations
+            .iter()
+            .map(|(stage, stats)| (stage.key(), stats.into()))
+            .collect(),
+        bench: result.benchmark.as_ref().map(|bench| BenchRecord {
+            iterations: bench.iterations,
+            warmup: bench.warmup,
+            stages: bench
+                .stages
+                .iter()
+                .map(|(stage, stats)| (stage.key(), stats_record(stats, bench.input_bytes)))
+                .chain([("total", stats_record(&bench.total, bench.input_bytes))])
+                .collect(),
+        }),
+    })
+}
+
+fn csv_field(value: &str) -> String {
+    match value.contains([',', '"', '\n']) {
+        true => format!("\"{}\"", value.replace('"', "\"\"")),
+        false => value.to_string(),
+    }
+}
+
+fn csv_header() -> String {
+    let stages = CSV_STAGES
+        .iter()
+        .map(|s| format!("{}_ns", s.key()))
+        .collect::<Vec<String>>()
+        .join(",");
+
+    format!(
+        "year,day,input,part,answer,status,expected,{},total_ns,message",
+        stages
+    )
+}
+
+fn csv_row(record: &Record) -> String {
+    let timings = CSV_STAGES
+        .iter()
+        .map(|s| {
+            record
+                .timings_ns
+                .get(s.key())
+                .map_or(String::new(), |t| t.to_string())
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+
+    format!(
+        "{},{},{},{},{},{},{},{},{},",
+        record.year,
+        record.day,
+        csv_field(record.input),
+        record.part,
+        csv_field(record.answer.as_deref().unwrap_or("")),
+        record.status.as_deref().unwrap_or(""),
+        csv_field(record.expected.unwrap_or("")),
+        timings,
+        record.timings_ns["total"],
+    )
+}
+
+/// A row that is empty but for the day, its status and the message.
+fn csv_error_row(record: &ErrorRecord) -> String {
+    format!(
+        "{},{},,,,{},,{},{}",
+        record.year,
+        record.day,
+        record.status,
+        ",".repeat(CSV_STAGES.len()),
+        csv_field(record.message)
+    )
+}
+
+fn text(result: &DayResult) -> String {
+    let mut lines = match result.input.as_str() {
+        "input" => vec![format!(
+            "Advent of Code {}, Day {}!",
+            result.day.year, result.day.day
+        )],
+        name => vec![format!(
+            "Advent of Code {}, Day {}! ({})",
+            result.day.year, result.day.day, name
+        )],
+    };
+
+    for part in &result.parts {
+        let value = match &part.answer {
+            Some(v) => format!("{}", v),
+            None => "No value!".to_string(),
+        };
+
+        let status = match (part.status, &part.expected) {
+            (Some(status @ crate::Status::Fail), Some(expected)) => {
+                format!(" ({}, expected {})", status, expected)
+            }
+            (Some(status), _) => format!(" ({})", status),
+            (None, _) => String::new(),
+        };
+
+        lines.push(format!(
+            "Day {} Result, Part {}: {}{}",
+            result.day.day, part.part, value, status
+        ));
+    }
+
+    for (stage, time_taken) in &result.timings {
+        lines.push(format!("Time Taken ({}): {:?}", stage, time_taken));
+    }
+
+    lines.push(format!("Time Taken: {:?}", result.total_time()));
+
+    for (stage, stats) in &result.allocations {
+        lines.push(format!("Allocated ({}): {}", stage, stats));
+    }
+
+    if let Some(benchmark) = &result.benchmark {
+        lines.push(benchmark.to_string().trim_end().to_string());
+    }
+
+    if let Some(path) = &result.recorded {
+        lines.push(format!("Recorded answers to {}", path.display()));
+    }
+
+    return lines.join("\n");
+}
+
+/// Streams day results to stdout in the chosen [`Format`].
+pub struct Printer {
+    format: Format,
+    printed: usize,
+    /// Text results are gathered into one table instead, when set.
+    summary: Option<Summary>,
+}
+
+impl Printer {
+    pub fn new(format: Format) -> Self {
+        return Self {
+            format,
+            printed: 0,
+            summary: None,
+        };
+    }
+
+    /// Like [`Printer::new`], but text output is a single summary table
+    /// printed by [`Printer::finish`].
+    pub fn summary(format: Format) -> Self {
+        return Self {
+            summary: Some(Summary::default()),
+            ..Self::new(format)
+        };
+    }
+
+    /// Notes a day that could not be run. Structured output gets a record
+    /// with the message, while text leaves it to stderr.
+    pub fn error(&mut self, day: Day, message: &str) {
+        let record = ErrorRecord::new(day, message);
+
+        match self.format {
+            Format::Text => {
+                if let Some(summary) = &mut self.summary {
+                    summary.push_error(day);
+                }
+            }
+            Format::Json => self.json(&record),
+            Format::Csv => {
+                self.csv_header();
+                println!("{}", csv_error_row(&record));
+                self.printed += 1;
+            }
+        }
+    }
+
+    fn json(&mut self, record: &impl Serialize) {
+        let separator = if self.printed == 0 { "[" } else { "," };
+        let json = serde_json::to_string(record).expect("Failed to serialise record");
+        print!("{}\n  {}", separator, json);
+        self.printed += 1;
+    }
+
+    fn csv_header(&self) {
+        if self.printed == 0 {
+            println!("{}", csv_header());
+        }
+    }
+
+    pub fn print(&mut self, result: &DayResult) {
+        match self.format {
+            Format::Text => match &mut self.summary {
+                Some(summary) => summary.push(result.clone()),
+                None => println!("{}", text(result)),
+            },
+            Format::Json => {
+                for record in records(result) {
+                    self.json(&record);
+                }
+            }
+            Format::Csv => {
+                self.csv_header();
+
+                for record in records(result) {
+                    println!("{}", csv_row(&record));
+                    self.printed += 1;
+                }
+            }
+        }
+    }
+
+    pub fn finish(self) {
+        match (self.format, self.printed, self.summary) {
+            (Format::Text, _, Some(summary)) => {
+                println!("{}", summary.render(std::io::stdout().is_terminal()))
+            }
+            (Format::Json, 0, _) => println!("[]"),
+            (Format::Json, _, _) => println!("\n]"),
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::{csv_error_row, csv_field, csv_header, csv_row, records, ErrorRecord};
+    use crate::{
+        runner::{DayResult, PartResult},
+        Answer, Day, Stage, Status,
+    };
+
+    fn result() -> DayResult {
+        DayResult {
+            day: Day::new(2023, 5),
+            input: "input".to_string(),
+            parts: [
+                PartResult {
+                    part: 1,
+                    answer: Some(Answer::from(35)),
+                    expected: Some("35".to_string()),
+                    status: Some(Status::Pass),
+                },
+                PartResult {
+                    part: 2,
+                    answer: Some(Answer::from("a,b")),
+                    expected: None,
+                    status: Some(Status::New),
+                },
+            ],
+            timings: vec![
+                (Stage::Parse, Duration::from_nanos(10)),
+                (Stage::Part1, Duration::from_nanos(20)),
+                (Stage::Part2, Duration::from_nanos(30)),
+            ],
+            allocations: vec![],
+            benchmark: None,
+            recorded: None,
+        }
+    }
+
+    #[test]
+    fn test_json_records() {
+        let result = result();
+        let json: Vec<String> = records(&result)
+            .map(|r| serde_json::to_string(&r).unwrap())
+            .collect();
+
+        assert_eq!(
+            json[0],
+            "{\"year\":2023,\"day\":5,\"input\":\"input\",\"part\":1,\"answer\":\"35\",\"status\":\"PASS\",\
+             \"expected\":\"35\",\"timings_ns\":{\"parse\":10,\"part1\":20,\"part2\":30,\"total\":60}}"
+        );
+    }
+
+    #[test]
+    fn test_csv_rows() {
+        let result = result();
+        let rows: Vec<String> = records(&result).map(|r| csv_row(&r)).collect();
+
+        assert_eq!(
+            csv_header(),
+            "year,day,input,part,answer,status,expected,parse_ns,part1_ns,part2_ns,solve_ns,total_ns,message"
+        );
+        assert_eq!(rows[0], "2023,5,input,1,35,PASS,35,10,20,30,,60,");
+        assert_eq!(rows[1], "2023,5,input,2,\"a,b\",NEW,,10,20,30,,60,");
+    }
+
+    #[test]
+    fn test_error_records() {
+        let record = ErrorRecord::new(Day::new(2023, 5), "Could not read input, it is gone");
+
+        assert_eq!(
+            serde_json::to_string(&record).unwrap(),
+            "{\"year\":2023,\"day\":5,\"status\":\"error\",\"message\":\"Could not read input, it is gone\"}"
+        );
+
+        let row = csv_error_row(&record);
+        assert_eq!(
+            row,
+            "2023,5,,,,error,,,,,,,\"Could not read input, it is gone\""
+        );
+    }
+
+    #[test]
+    fn test_csv_field_quoting() {
+        assert_eq!(csv_field("plain"), "plain");
+        assert_eq!(csv_field("say \"hi\""), "\"say \"\"hi\"\"\"");
+    }
+}
@@ -0,0 +1,261 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+    str::FromStr,
+};
+
+use crate::Position;
+
+/// A point on an unbounded plane, with rows counting down and columns
+/// counting right, as in a [`Grid`](crate::Grid).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Point {
+    pub row: isize,
+    pub col: isize,
+}
+
+/// The displacement from one [`Point`] to another.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Vector {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Point {
+    pub const ORIGIN: Point = Point::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        return Self { row, col };
+    }
+
+    /// Steps along rows and columns between the two points.
+    pub fn manhattan(self, other: Point) -> usize {
+        (self - other).manhattan()
+    }
+
+    /// The point as a grid position, unless it is above or left of the grid.
+    pub fn position(self) -> Option<Position> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+}
+
+impl From<Position> for Point {
+    fn from((row, col): Position) -> Self {
+        Self::new(row as isize, col as isize)
+    }
+}
+
+impl Vector {
+    pub const fn new(row: isize, col: isize) -> Self {
+        return Self { row, col };
+    }
+
+    /// Steps along rows and columns the vector covers.
+    pub fn manhattan(self) -> usize {
+        self.row.unsigned_abs() + self.col.unsigned_abs()
+    }
+}
+
+impl Add<Vector> for Point {
+    type Output = Point;
+
+    fn add(self, rhs: Vector) -> Point {
+        Point::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign<Vector> for Point {
+    fn add_assign(&mut self, rhs: Vector) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub<Vector> for Point {
+    type Output = Point;
+
+    fn sub(self, rhs: Vector) -> Point {
+        self + -rhs
+    }
+}
+
+impl SubAssign<Vector> for Point {
+    fn sub_assign(&mut self, rhs: Vector) {
+        *self = *self - rhs;
+    }
+}
+
+impl Sub for Point {
+    type Output = Vector;
+
+    fn sub(self, rhs: Point) -> Vector {
+        Vector::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl Add for Vector {
+    type Output = Vector;
+
+    fn add(self, rhs: Vector) -> Vector {
+        Vector::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl Sub for Vector {
+    type Output = Vector;
+
+    fn sub(self, rhs: Vector) -> Vector {
+        self + -rhs
+    }
+}
+
+impl Neg for Vector {
+    type Output = Vector;
+
+    fn neg(self) -> Vector {
+        Vector::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Vector {
+    type Output = Vector;
+
+    fn mul(self, rhs: isize) -> Vector {
+        Vector::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+/// One of the four grid directions, with north pointing up the rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Direction {
+    /// Every direction, clockwise from north.
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    /// A single step in this direction.
+    pub const fn vector(self) -> Vector {
+        match self {
+            Direction::North => Vector::new(-1, 0),
+            Direction::East => Vector::new(0, 1),
+            Direction::South => Vector::new(1, 0),
+            Direction::West => Vector::new(0, -1),
+        }
+    }
+
+    pub const fn turn_right(self) -> Self {
+        match self {
+            Direction::North => Direction::East,
+            Direction::East => Direction::South,
+            Direction::South => Direction::West,
+            Direction::West => Direction::North,
+        }
+    }
+
+    pub const fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub const fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+}
+
+/// A character that names no direction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(pub String);
+
+impl Display for ParseDirectionError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected one of U, D, L, R, N, E, S or W, got `{}`",
+            self.0
+        )
+    }
+}
+
+impl std::error::Error for ParseDirectionError {}
+
+/// `U`, `D`, `L` and `R`, or the compass letters `N`, `E`, `S` and `W`.
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        match value {
+            'U' | 'N' => Ok(Direction::North),
+            'R' | 'E' => Ok(Direction::East),
+            'D' | 'S' => Ok(Direction::South),
+            'L' | 'W' => Ok(Direction::West),
+            _ => Err(ParseDirectionError(value.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction, Point, Vector};
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Point::new(2, -3);
+        let b = Point::new(-1, 1);
+
+        assert_eq!(a - b, Vector::new(3, -4));
+        assert_eq!(b + (a - b), a);
+        assert_eq!(a + Vector::new(1, 1) * 3, Point::new(5, 0));
+        assert_eq!(a - Direction::North.vector(), Point::new(3, -3));
+        assert_eq!(a.manhattan(b), 7);
+        assert_eq!(Point::ORIGIN.manhattan(Point::ORIGIN), 0);
+
+        assert_eq!(Point::from((4, 5)).position(), Some((4, 5)));
+        assert_eq!(a.position(), None);
+    }
+
+    #[test]
+    fn test_turns() {
+        for direction in Direction::ALL {
+            assert_eq!(direction.turn_left().turn_right(), direction);
+            assert_eq!(direction.reverse().vector(), -direction.vector());
+        }
+
+        assert_eq!(Direction::North.turn_right(), Direction::East);
+        assert_eq!(Direction::North.turn_left(), Direction::West);
+        assert_eq!(Direction::East.reverse(), Direction::West);
+    }
+
+    #[test]
+    fn test_parse_direction() {
+        assert_eq!("U".parse(), Ok(Direction::North));
+        assert_eq!("L".parse(), Ok(Direction::West));
+        assert_eq!(Direction::try_from('E'), Ok(Direction::East));
+        assert_eq!(Direction::try_from('S'), Ok(Direction::South));
+        assert!("UD".parse::<Direction>().is_err());
+        assert_eq!(
+            "x".parse::<Direction>().unwrap_err().to_string(),
+            "expected one of U, D, L, R, N, E, S or W, got `x`"
+        );
+    }
+}
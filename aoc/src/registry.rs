@@ -0,0 +1,124 @@
+use std::collections::BTreeMap;
+
+use crate::{
+    gen::Generate,
+    solution::{Raw, Solver, Staged},
+    Day, Result, Solution,
+};
+
+/// Hook exported by each day crate to add its solver to a [`Registry`].
+pub type Register = fn(&mut Registry);
+
+#[derive(Default)]
+pub struct Registry {
+    solvers: BTreeMap<Day, Box<dyn Solver>>,
+    generators: BTreeMap<Day, Generate>,
+}
+
+impl Registry {
+    pub fn new() -> Self {
+        return Self::default();
+    }
+
+    /// Registers a function that solves both parts in one go.
+    pub fn register(&mut self, day: Day, solver: fn(&str) -> Result) {
+        self.insert(day, Box::new(solver));
+    }
+
+    /// Registers a function like [`register`](Self::register), but hands it
+    /// the input without normalising it first.
+    pub fn register_raw(&mut self, day: Day, solver: fn(&str) -> Result) {
+        self.insert(day, Box::new(Raw(solver)));
+    }
+
+    /// Registers a [`Solution`], timing its parse and part stages separately.
+    pub fn register_solution<S: Solution + 'static>(&mut self, day: Day) {
+        self.insert(day, Box::new(Staged::<S>::new()));
+    }
+
+    /// Registers a generator of random inputs in the day's format.
+    pub fn register_generator(&mut self, day: Day, generate: Generate) {
+        if self.generators.insert(day, generate).is_some() {
+            panic!("Day {} generator registered twice", day);
+        }
+    }
+
+    fn insert(&mut self, day: Day, solver: Box<dyn Solver>) {
+        if self.solvers.insert(day, solver).is_some() {
+            panic!("Day {} registered twice", day);
+        }
+    }
+
+    pub fn get(&self, day: Day) -> Option<&dyn Solver> {
+        self.solvers.get(&day).map(|solver| solver.as_ref())
+    }
+
+    pub fn generator(&self, day: Day) -> Option<Generate> {
+        self.generators.get(&day).copied()
+    }
+
+    /// The latest year with a registered day.
+    pub fn latest_year(&self) -> Option<u16> {
+        self.solvers.keys().next_back().map(|day| day.year)
+    }
+
+    pub fn days(&self) -> impl Iterator<Item = (Day, &dyn Solver)> + '_ {
+        self.solvers
+            .iter()
+            .map(|(day, solver)| (*day, solver.as_ref()))
+    }
+}
+
+impl From<&[Register]> for Registry {
+    fn from(value: &[Register]) -> Self {
+        let mut registry = Self::new();
+
+        for register in value {
+            register(&mut registry);
+        }
+
+        return registry;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Register, Registry};
+    use crate::Day;
+
+    fn day_one(registry: &mut Registry) {
+        registry.register(Day::new(2023, 1), |_| (Some(1.into()), None));
+    }
+
+    fn day_two(registry: &mut Registry) {
+        registry.register(Day::new(2023, 2), |_| (None, Some(2.into())));
+    }
+
+    fn next_year(registry: &mut Registry) {
+        registry.register(Day::new(2024, 1), |_| (None, None));
+    }
+
+    #[test]
+    fn test_registry_from_hooks() {
+        let hooks: &[Register] = &[next_year, day_two, day_one];
+        let registry = Registry::from(hooks);
+
+        let days: Vec<Day> = registry.days().map(|(day, _)| day).collect();
+        assert_eq!(
+            days,
+            vec![Day::new(2023, 1), Day::new(2023, 2), Day::new(2024, 1)]
+        );
+        assert_eq!(registry.latest_year(), Some(2024));
+
+        let report = registry.get(Day::new(2023, 2)).map(|s| s.solve(""));
+        assert_eq!(report.and_then(|r| r.part_2), Some(2.into()));
+        assert!(registry.get(Day::new(2024, 2)).is_none());
+    }
+
+    #[test]
+    #[should_panic(expected = "Day 2023/1 registered twice")]
+    fn test_registry_duplicate_day() {
+        let hooks: &[Register] = &[day_one, day_one];
+        let _ = Registry::from(hooks);
+    }
+}
@@ -0,0 +1,278 @@
+use std::{
+    borrow::Cow,
+    io,
+    path::{Path, PathBuf},
+    time::{Duration, Instant},
+};
+
+use crate::{
+    answers::{default_answers_path, example_answers_path, AnswersError, KnownAnswers, Status},
+    bench, cancel,
+    history::{self, Entry, History},
+    input, log, AllocStats, Answer, Benchmark, Day, Input, Solver, Stage,
+};
+
+#[derive(Debug, Clone, Default)]
+pub struct Options {
+    /// Save the computed answers as the known answers.
+    pub record: bool,
+    /// Benchmark the solver over this many timed runs.
+    pub bench: Option<usize>,
+    /// Time budget for each day, after which [`CancelToken`](crate::CancelToken)
+    /// cancels and unanswered parts are reported as timed out.
+    pub timeout: Option<Duration>,
+    /// Append the timings to `./dayN/history.jsonl`.
+    pub history: bool,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PartResult {
+    pub part: u8,
+    pub answer: Option<Answer>,
+    pub expected: Option<String>,
+    pub status: Option<Status>,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayResult {
+    pub day: Day,
+    /// Name of the input, used as its section in the answers file.
+    pub input: String,
+    pub parts: [PartResult; 2],
+    pub timings: Vec<(Stage, Duration)>,
+    /// Empty unless allocations are being counted.
+    pub allocations: Vec<(Stage, AllocStats)>,
+    pub benchmark: Option<Benchmark>,
+    /// Where the answers were saved, when recording.
+    pub recorded: Option<PathBuf>,
+}
+
+impl DayResult {
+    pub fn total_time(&self) -> Duration {
+        self.timings.iter().map(|(_, time)| *time).sum()
+    }
+
+    /// `false` if any part disagrees with a known answer or timed out.
+    pub fn passed(&self) -> bool {
+        self.parts
+            .iter()
+            .all(|p| !matches!(p.status, Some(Status::Fail | Status::Timeout)))
+    }
+}
+
+/// Runs a day and checks its answers against `answers.toml` in its crate,
+/// or `examples/answers.toml` for example inputs. Fails without solving if
+/// the answers cannot be read, or after solving if they cannot be recorded.
+pub fn run(
+    day: Day,
+    solver: &dyn Solver,
+    input: &Input,
+    options: &Options,
+) -> Result<DayResult, AnswersError> {
+    let answers_path = match input.example {
+        true => example_answers_path(day),
+        false => default_answers_path(day),
+    };
+
+    return run_with_answers(day, solver, input, options, &answers_path);
+}
+
+fn run_with_answers(
+    day: Day,
+    solver: &dyn Solver,
+    input: &Input,
+    options: &Options,
+    answers_path: &Path,
+) -> Result<DayResult, AnswersError> {
+    let input_name = &input.name;
+    let mut answers = KnownAnswers::load(answers_path)
+        .map_err(|e| AnswersError::Read(answers_path.to_owned(), e))?;
+
+    let contents = match solver.raw_input() {
+        true => Cow::Borrowed(input.contents.as_str()),
+        false => Cow::Owned(input::normalise(&input.contents)),
+    };
+
+    let deadline = options.timeout.map(|timeout| Instant::now() + timeout);
+    let report = log::with_day(day, || {
+        cancel::with_deadline(deadline, || solver.solve(&contents))
+    });
+    let timed_out = deadline.is_some_and(|deadline| Instant::now() >= deadline);
+
+    let parts = [(1, report.part_1), (2, report.part_2)].map(|(part, answer)| PartResult {
+        part,
+        status: match (&answer, timed_out) {
+            (None, true) => Some(Status::Timeout),
+            _ => answers.check(input_name, part, answer.as_ref()),
+        },
+        expected: answers.get(input_name, part),
+        answer,
+    });
+
+    let benchmark = match timed_out {
+        true => None,
+        false => options
+            .bench
+            .map(|iterations| log::muted(|| bench::bench(solver, &contents, iterations))),
+    };
+
+    let recorded = match options.record && !timed_out {
+        true => {
+            for part in &parts {
+                if let Some(answer) = &part.answer {
+                    answers.record(input_name, part.part, answer);
+                }
+            }
+
+            answers
+                .save(answers_path)
+                .map_err(|e| AnswersError::Write(answers_path.to_owned(), e))?;
+
+            Some(answers_path.to_owned())
+        }
+        false => None,
+    };
+
+    let result = DayResult {
+        day,
+        input: input_name.clone(),
+        parts,
+        timings: report.timings,
+        allocations: report.allocations,
+        benchmark,
+        recorded,
+    };
+
+    // A day without its own directory, e.g. one run from elsewhere, simply
+    // has no history.
+    if options.history && !timed_out {
+        let path = history::default_history_path(day);
+        match History::append(&path, &Entry::new(&result)) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => eprintln!("Could not write history to {}: {}", path.display(), e),
+        }
+    }
+
+    return Ok(result);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{fs, time::Duration};
+
+    use super::{run, run_with_answers, Options};
+    use crate::{
+        answers::AnswersError, solution::Staged, Answer, CancelToken, Day, Input, Registry, Result,
+        Solution, Solver, Status,
+    };
+
+    fn spin(_: &str) -> Result {
+        let cancel = CancelToken::current();
+        while !cancel.is_cancelled() {}
+
+        return (Some(1.into()), None);
+    }
+
+    /// Counts the carriage returns and lines it was given.
+    struct Shape<const RAW: bool>;
+
+    impl<const RAW: bool> Solution for Shape<RAW> {
+        type Input = String;
+
+        const RAW_INPUT: bool = RAW;
+
+        fn parse(input: &str) -> String {
+            input.to_string()
+        }
+
+        fn part1(input: &String) -> Option<Answer> {
+            Some(input.matches('\r').count().into())
+        }
+
+        fn part2(input: &String) -> Option<Answer> {
+            Some(input.lines().count().into())
+        }
+    }
+
+    /// [`Shape`] as a plain function.
+    fn shape(input: &str) -> Result {
+        let input = input.to_string();
+        return (Shape::<true>::part1(&input), Shape::<true>::part2(&input));
+    }
+
+    fn input(contents: &str) -> Input {
+        Input {
+            name: "input".to_string(),
+            contents: contents.to_string(),
+            example: false,
+        }
+    }
+
+    #[test]
+    fn test_run_normalises_input() {
+        let crlf = input("1 2\r\n3 4  \r\n\r\n");
+        let answers = |solver: &dyn Solver| {
+            let result = run(Day::new(2023, 0), solver, &crlf, &Options::default()).unwrap();
+            result.parts.map(|p| p.answer.unwrap())
+        };
+
+        assert_eq!(
+            answers(&Staged::<Shape<false>>::new()),
+            [0.into(), 2.into()]
+        );
+        assert_eq!(answers(&Staged::<Shape<true>>::new()), [3.into(), 3.into()]);
+
+        let day = Day::new(2023, 0);
+        let mut registry = Registry::new();
+        registry.register(day, shape);
+        assert_eq!(answers(registry.get(day).unwrap()), [0.into(), 2.into()]);
+
+        let mut registry = Registry::new();
+        registry.register_raw(day, shape);
+        assert_eq!(answers(registry.get(day).unwrap()), [3.into(), 3.into()]);
+    }
+
+    #[test]
+    fn test_run_timeout() {
+        let solver: fn(&str) -> Result = spin;
+        let input = input("");
+        let options = Options {
+            timeout: Some(Duration::from_millis(10)),
+            ..Options::default()
+        };
+
+        let result = run(Day::new(2023, 0), &solver as &dyn Solver, &input, &options).unwrap();
+
+        assert_eq!(result.parts[0].status, Some(Status::New));
+        assert_eq!(result.parts[1].status, Some(Status::Timeout));
+        assert!(!result.passed());
+    }
+
+    #[test]
+    fn test_run_bad_answers() {
+        let dir = std::env::temp_dir().join(format!("aoc-runner-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let solver: fn(&str) -> Result = |_| (Some(1.into()), None);
+        let solver = &solver as &dyn Solver;
+        let input = input("");
+        let day = Day::new(2023, 0);
+
+        let malformed = dir.join("answers.toml");
+        fs::write(&malformed, "[input\npart1 = ").unwrap();
+        let result = run_with_answers(day, solver, &input, &Options::default(), &malformed);
+        assert!(matches!(result, Err(AnswersError::Read(path, _)) if path == malformed));
+
+        let record = Options {
+            record: true,
+            ..Options::default()
+        };
+        let unwritable = dir.join("missing").join("answers.toml");
+        let result = run_with_answers(day, solver, &input, &record, &unwritable);
+        assert!(matches!(result, Err(AnswersError::Write(path, _)) if path == unwritable));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+}
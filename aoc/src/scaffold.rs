@@ -0,0 +1,292 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::{Day, ROOT_YEAR};
+
+const CARGO_TEMPLATE: &str = include_str!("../templates/Cargo.toml.tmpl");
+const LIB_TEMPLATE: &str = include_str!("../templates/lib.rs.tmpl");
+const MAIN_TEMPLATE: &str = include_str!("../templates/main.rs.tmpl");
+
+#[derive(Debug)]
+pub enum ScaffoldError {
+    /// The day's crate is already there, and is never overwritten.
+    Exists(PathBuf),
+    /// The `aoc` binary's manifest or day list, or the workspace members,
+    /// could not be found in the file.
+    Unregistered(PathBuf),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for ScaffoldError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            ScaffoldError::Exists(path) => write!(f, "{} already exists", path.display()),
+            ScaffoldError::Unregistered(path) => {
+                write!(f, "Could not find the list of days in {}", path.display())
+            }
+            ScaffoldError::Io(path, error) => {
+                write!(f, "Could not write {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+/// Name of the day's library crate, as used in Rust paths.
+fn crate_name(day: Day) -> String {
+    day.package().replace('-', "_")
+}
+
+fn render(template: &str, day: Day) -> String {
+    let root = match day.year {
+        ROOT_YEAR => "../",
+        _ => "../../",
+    };
+
+    template
+        .replace("{{day}}", &day.day.to_string())
+        .replace("{{year}}", &day.year.to_string())
+        .replace("{{package}}", &day.package())
+        .replace("{{crate}}", &crate_name(day))
+        .replace("{{root}}", root)
+}
+
+/// Inserts `line` among the lines for each day, found by `day_of`, keeping
+/// them in year and day order. `None` when there are no such lines to insert
+/// among.
+fn insert_day(
+    contents: &str,
+    day: Day,
+    line: &str,
+    day_of: impl Fn(&str) -> Option<Day>,
+) -> Option<String> {
+    let mut lines: Vec<&str> = contents.lines().collect();
+
+    let days: Vec<(usize, Day)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, l)| day_of(l).map(|d| (i, d)))
+        .collect();
+
+    let (last, _) = days.last()?;
+    let index = days
+        .iter()
+        .find(|(_, d)| *d > day)
+        .map_or(last + 1, |(i, _)| *i);
+
+    lines.insert(index, line);
+
+    return Some(lines.join("\n") + "\n");
+}
+
+/// Parses `N` or `N<separator>YYYY`, the end of a package or crate name.
+fn parse_day(name: &str, separator: char) -> Option<Day> {
+    let (day, year) = match name.split_once(separator) {
+        Some((day, year)) => (day, year.parse().ok()?),
+        None => (name, ROOT_YEAR),
+    };
+
+    return Some(Day::new(year, day.parse().ok()?));
+}
+
+/// Parses the day out of `dayN = { path = "../dayN" }`, or
+/// `dayN-YYYY = { path = "../YYYY/dayN" }`.
+fn dependency_day(line: &str) -> Option<Day> {
+    parse_day(line.strip_prefix("day")?.split_once(" = ")?.0, '-')
+}
+
+/// Parses the day out of `    dayN::register,`, or `    dayN_YYYY::register,`.
+fn register_day(line: &str) -> Option<Day> {
+    let name = line
+        .trim()
+        .strip_prefix("day")?
+        .strip_suffix("::register,")?;
+
+    parse_day(name, '_')
+}
+
+/// Adds `YYYY/day*` to the workspace members in `manifest`, unless it is
+/// already there.
+fn add_member(manifest: &str, year: u16) -> Option<String> {
+    let member = format!("\"{}/day*\"", year);
+    if manifest.contains(&member) {
+        return Some(manifest.to_string());
+    }
+
+    manifest
+        .contains("\"day*\"")
+        .then(|| manifest.replacen("\"day*\"", &format!("\"day*\", {}", member), 1))
+}
+
+/// Creates the day's crate under `root` from the templates, and adds it to
+/// the `aoc` binary in `root/cli`, and for a year other than [`ROOT_YEAR`] to
+/// the workspace. Returns the files written.
+pub fn new_day(root: &Path, day: Day) -> Result<Vec<PathBuf>, ScaffoldError> {
+    let relative = day.dir();
+    let dir = root.join(relative.strip_prefix(".").unwrap_or(&relative));
+    if dir.exists() {
+        return Err(ScaffoldError::Exists(dir));
+    }
+
+    let manifest = root.join("cli").join("Cargo.toml");
+    let days = root.join("cli").join("src").join("lib.rs");
+    let read = |path: &Path| {
+        fs::read_to_string(path).map_err(|e| ScaffoldError::Io(path.to_path_buf(), e))
+    };
+
+    // Both registrations are worked out before anything is written, so a
+    // failure leaves the workspace untouched.
+    let dependency = format!(
+        "{} = {{ path = \"../{}\" }}",
+        day.package(),
+        relative.strip_prefix(".").unwrap_or(&relative).display()
+    );
+    let new_manifest = insert_day(&read(&manifest)?, day, &dependency, dependency_day)
+        .ok_or_else(|| ScaffoldError::Unregistered(manifest.clone()))?;
+
+    let register = format!("    {}::register,", crate_name(day));
+    let new_days = insert_day(&read(&days)?, day, &register, register_day)
+        .ok_or_else(|| ScaffoldError::Unregistered(days.clone()))?;
+
+    let workspace = root.join("Cargo.toml");
+    let new_workspace = match day.year {
+        ROOT_YEAR => None,
+        year => Some(
+            add_member(&read(&workspace)?, year)
+                .ok_or_else(|| ScaffoldError::Unregistered(workspace.clone()))?,
+        ),
+    };
+
+    let mut files = vec![
+        (dir.join("Cargo.toml"), render(CARGO_TEMPLATE, day)),
+        (dir.join("src").join("lib.rs"), render(LIB_TEMPLATE, day)),
+        (dir.join("src").join("main.rs"), render(MAIN_TEMPLATE, day)),
+        (dir.join("answers.toml"), String::new()),
+        (dir.join("examples").join("example.txt"), String::new()),
+        (manifest, new_manifest),
+        (days, new_days),
+    ];
+    files.extend(new_workspace.map(|contents| (workspace, contents)));
+
+    for (path, contents) in &files {
+        let to_error = |e| ScaffoldError::Io(path.clone(), e);
+        fs::create_dir_all(path.parent().unwrap()).map_err(to_error)?;
+        fs::write(path, contents).map_err(to_error)?;
+    }
+
+    return Ok(files.into_iter().map(|(path, _)| path).collect());
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::{new_day, ScaffoldError};
+    use crate::Day;
+
+    const MANIFEST: &str = "\
+        [dependencies]\n\
+        aoc = { path = \"../aoc\" }\n\
+        day1 = { path = \"../day1\" }\n\
+        day3 = { path = \"../day3\" }\n\
+        \n\
+        [lints]\n\
+        workspace = true\n\
+    ";
+
+    const DAYS: &str = "\
+        pub const DAYS: &[aoc::Register] = &[\n    \
+            day1::register,\n    \
+            day3::register,\n\
+        ];\n\
+    ";
+
+    #[test]
+    fn test_new_day() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("cli/src")).unwrap();
+        fs::write(root.join("cli/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("cli/src/lib.rs"), DAYS).unwrap();
+
+        new_day(&root, Day::new(2023, 2)).unwrap();
+        new_day(&root, Day::new(2023, 20)).unwrap();
+
+        let manifest = fs::read_to_string(root.join("cli/Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "day1 = { path = \"../day1\" }\n\
+             day2 = { path = \"../day2\" }\n\
+             day3 = { path = \"../day3\" }\n\
+             day20 = { path = \"../day20\" }\n\n"
+        ));
+
+        let days = fs::read_to_string(root.join("cli/src/lib.rs")).unwrap();
+        assert!(days.contains(
+            "    day1::register,\n    day2::register,\n    day3::register,\n    day20::register,\n];"
+        ));
+
+        let lib = fs::read_to_string(root.join("day20/src/lib.rs")).unwrap();
+        assert!(lib.contains("pub const DAY: aoc::Day = aoc::Day::new(2023, 20);"));
+        assert!(lib.contains("registry.register_solution::<Day20>(DAY);"));
+        let main = fs::read_to_string(root.join("day20/src/main.rs")).unwrap();
+        assert!(main.contains("aoc::day_main(day20::register)"));
+        assert_eq!(
+            fs::read_to_string(root.join("day20/answers.toml")).unwrap(),
+            ""
+        );
+
+        fs::write(root.join("day20/src/lib.rs"), "// solved").unwrap();
+        assert!(matches!(
+            new_day(&root, Day::new(2023, 20)),
+            Err(ScaffoldError::Exists(_))
+        ));
+        assert_eq!(
+            fs::read_to_string(root.join("day20/src/lib.rs")).unwrap(),
+            "// solved"
+        );
+    }
+
+    #[test]
+    fn test_new_day_in_another_year() {
+        let root = std::env::temp_dir().join(format!("aoc-scaffold-year-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&root);
+        fs::create_dir_all(root.join("cli/src")).unwrap();
+        fs::write(root.join("cli/Cargo.toml"), MANIFEST).unwrap();
+        fs::write(root.join("cli/src/lib.rs"), DAYS).unwrap();
+
+        assert!(matches!(
+            new_day(&root, Day::new(2024, 1)),
+            Err(ScaffoldError::Io(..))
+        ));
+
+        fs::write(
+            root.join("Cargo.toml"),
+            "[workspace]\nmembers = [\"day*\", \"aoc\"]\n",
+        )
+        .unwrap();
+        new_day(&root, Day::new(2024, 2)).unwrap();
+        new_day(&root, Day::new(2024, 1)).unwrap();
+
+        let workspace = fs::read_to_string(root.join("Cargo.toml")).unwrap();
+        assert!(workspace.contains("members = [\"day*\", \"2024/day*\", \"aoc\"]"));
+
+        let manifest = fs::read_to_string(root.join("cli/Cargo.toml")).unwrap();
+        assert!(manifest.contains(
+            "day3 = { path = \"../day3\" }\n\
+             day1-2024 = { path = \"../2024/day1\" }\n\
+             day2-2024 = { path = \"../2024/day2\" }\n\n"
+        ));
+
+        let days = fs::read_to_string(root.join("cli/src/lib.rs")).unwrap();
+        assert!(days
+            .contains("    day3::register,\n    day1_2024::register,\n    day2_2024::register,"));
+
+        let cargo = fs::read_to_string(root.join("2024/day1/Cargo.toml")).unwrap();
+        assert!(cargo.contains("name = \"day1-2024\""));
+        assert!(cargo.contains("aoc = { path = \"../../aoc\" }"));
+        let main = fs::read_to_string(root.join("2024/day1/src/main.rs")).unwrap();
+        assert!(main.contains("aoc::day_main(day1_2024::register)"));
+    }
+}
@@ -0,0 +1,419 @@
+//! Shortest paths over any state space, described by a start state and a
+//! closure listing the states one move away from a given one.
+//!
+//! Once [`CancelToken::current`] is cancelled, the path searches give up and
+//! return `None`, as they do when no goal can be reached, and [`distances`]
+//! stops with only the states reached so far.
+
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, VecDeque},
+    hash::Hash,
+    ops::Add,
+};
+
+use crate::CancelToken;
+
+/// The cheapest way found to a goal.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+    pub cost: C,
+    /// Every state along the path, from the start to the goal.
+    pub states: Vec<S>,
+}
+
+impl<S, C> Path<S, C> {
+    pub fn goal(&self) -> &S {
+        self.states.last().expect("a path holds at least its start")
+    }
+}
+
+/// The fewest moves from `start` to a state matching `is_goal`.
+pub fn bfs<S, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let cancel = CancelToken::current();
+    let mut nodes = Nodes::new(start, 0);
+    let mut queue = VecDeque::from([0]);
+
+    while let Some(node) = queue.pop_front() {
+        if cancel.is_cancelled() {
+            return None;
+        }
+
+        if is_goal(&nodes.states[node].state) {
+            return Some(nodes.path(node));
+        }
+
+        let cost = nodes.states[node].cost + 1;
+        for next in successors(&nodes.states[node].state) {
+            if let Entry::Vacant(entry) = nodes.index.entry(next) {
+                queue.push_back(nodes.states.len());
+                nodes.states.push(Node {
+                    state: entry.key().clone(),
+                    parent: Some(node),
+                    cost,
+                    closed: false,
+                });
+                entry.insert(nodes.states.len() - 1);
+            }
+        }
+    }
+
+    return None;
+}
+
+/// The fewest moves from `start` to every state it can reach, itself
+/// included at 0. Stops early, with what it has found, if cancelled.
+pub fn distances<S, I>(start: S, mut successors: impl FnMut(&S) -> I) -> HashMap<S, usize>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = S>,
+{
+    let cancel = CancelToken::current();
+    let mut distances = HashMap::from([(start.clone(), 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(state) = queue.pop_front() {
+        if cancel.is_cancelled() {
+            break;
+        }
+
+        let distance = distances[&state] + 1;
+
+        for next in successors(&state) {
+            if let Entry::Vacant(entry) = distances.entry(next) {
+                queue.push_back(entry.key().clone());
+                entry.insert(distance);
+            }
+        }
+    }
+
+    return distances;
+}
+
+/// The cheapest path from `start` to a state matching `is_goal`, where
+/// `successors` gives each next state with the cost of moving to it.
+pub fn dijkstra<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    return astar(start, successors, |_| C::default(), is_goal);
+}
+
+/// [`dijkstra`], searching first where `heuristic` estimates the goal to be
+/// closest. The estimate must never be more than the real remaining cost, nor
+/// drop by more than the cost of a move, or the path found may not be the
+/// cheapest.
+pub fn astar<S, C, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> C,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    return search(start, successors, heuristic, is_goal, BinaryHeap::new());
+}
+
+/// [`dijkstra`] for small integer costs, keeping a bucket of states for each
+/// cost rather than a heap. Much faster when moves cost a few units each, as
+/// on a grid of digits, but the buckets grow with the cost of the path.
+pub fn dijkstra_buckets<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    return astar_buckets(start, successors, |_| 0, is_goal);
+}
+
+/// [`astar`] with the buckets of [`dijkstra_buckets`].
+pub fn astar_buckets<S, I>(
+    start: S,
+    successors: impl FnMut(&S) -> I,
+    heuristic: impl FnMut(&S) -> usize,
+    is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+    S: Clone + Eq + Hash,
+    I: IntoIterator<Item = (S, usize)>,
+{
+    return search(start, successors, heuristic, is_goal, Buckets::default());
+}
+
+struct Node<S, C> {
+    state: S,
+    parent: Option<usize>,
+    cost: C,
+    closed: bool,
+}
+
+/// Every state seen so far, with the cheapest known way to reach it.
+struct Nodes<S, C> {
+    states: Vec<Node<S, C>>,
+    index: HashMap<S, usize>,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy> Nodes<S, C> {
+    fn new(start: S, cost: C) -> Self {
+        return Self {
+            index: HashMap::from([(start.clone(), 0)]),
+            states: vec![Node {
+                state: start,
+                parent: None,
+                cost,
+                closed: false,
+            }],
+        };
+    }
+
+    fn path(&self, goal: usize) -> Path<S, C> {
+        let mut states = vec![];
+        let mut node = Some(goal);
+
+        while let Some(index) = node {
+            states.push(self.states[index].state.clone());
+            node = self.states[index].parent;
+        }
+
+        states.reverse();
+
+        return Path {
+            cost: self.states[goal].cost,
+            states,
+        };
+    }
+}
+
+/// Nodes waiting to be visited, cheapest estimate first.
+trait Frontier<C> {
+    fn push(&mut self, priority: C, node: usize);
+    fn pop(&mut self) -> Option<usize>;
+}
+
+impl<C: Ord> Frontier<C> for BinaryHeap<Reverse<(C, usize)>> {
+    fn push(&mut self, priority: C, node: usize) {
+        BinaryHeap::push(self, Reverse((priority, node)));
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        BinaryHeap::pop(self).map(|Reverse((_, node))| node)
+    }
+}
+
+/// A bucket queue: priorities only ever grow during a search, so the next
+/// node is always in the first bucket that is not empty.
+#[derive(Default)]
+struct Buckets {
+    buckets: Vec<Vec<usize>>,
+    current: usize,
+}
+
+impl Frontier<usize> for Buckets {
+    fn push(&mut self, priority: usize, node: usize) {
+        if priority >= self.buckets.len() {
+            self.buckets.resize_with(priority + 1, Vec::new);
+        }
+
+        self.buckets[priority.max(self.current)].push(node);
+    }
+
+    fn pop(&mut self) -> Option<usize> {
+        while self.current < self.buckets.len() {
+            if let Some(node) = self.buckets[self.current].pop() {
+                return Some(node);
+            }
+
+            self.current += 1;
+        }
+
+        return None;
+    }
+}
+
+fn search<S, C, I>(
+    start: S,
+    mut successors: impl FnMut(&S) -> I,
+    mut heuristic: impl FnMut(&S) -> C,
+    mut is_goal: impl FnMut(&S) -> bool,
+    mut frontier: impl Frontier<C>,
+) -> Option<Path<S, C>>
+where
+    S: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (S, C)>,
+{
+    let cancel = CancelToken::current();
+    let mut nodes = Nodes::new(start, C::default());
+    frontier.push(heuristic(&nodes.states[0].state), 0);
+
+    while let Some(node) = frontier.pop() {
+        if cancel.is_cancelled() {
+            return None;
+        }
+
+        // A node is queued again whenever a cheaper way to it turns up, so
+        // only its first, cheapest visit counts.
+        if nodes.states[node].closed {
+            continue;
+        }
+        nodes.states[node].closed = true;
+
+        if is_goal(&nodes.states[node].state) {
+            return Some(nodes.path(node));
+        }
+
+        let cost = nodes.states[node].cost;
+        for (next, step) in successors(&nodes.states[node].state) {
+            let next_cost = cost + step;
+
+            let next_node = match nodes.index.entry(next) {
+                Entry::Occupied(entry) => {
+                    let existing = &mut nodes.states[*entry.get()];
+                    if existing.closed || existing.cost <= next_cost {
+                        continue;
+                    }
+
+                    existing.cost = next_cost;
+                    existing.parent = Some(node);
+                    *entry.get()
+                }
+                Entry::Vacant(entry) => {
+                    nodes.states.push(Node {
+                        state: entry.key().clone(),
+                        parent: Some(node),
+                        cost: next_cost,
+                        closed: false,
+                    });
+                    *entry.insert(nodes.states.len() - 1)
+                }
+            };
+
+            let priority = next_cost + heuristic(&nodes.states[next_node].state);
+            frontier.push(priority, next_node);
+        }
+    }
+
+    return None;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{Duration, Instant};
+
+    use super::{astar, astar_buckets, bfs, dijkstra, dijkstra_buckets, distances, Path};
+    use crate::{cancel::with_deadline, Grid, Point, Position};
+
+    const MAZE: &str = "\
+..#....
+.##.##.
+...#...
+.#...#.
+";
+
+    fn open(grid: &Grid<char>, position: Position) -> Vec<Position> {
+        grid.neighbours4(position)
+            .filter(|&next| grid[next] == '.')
+            .collect()
+    }
+
+    #[test]
+    fn test_bfs() {
+        let grid = Grid::from(MAZE);
+        let end = (0, 6);
+
+        let path = bfs((0, 0), |&p| open(&grid, p), |&p| p == end).unwrap();
+
+        assert_eq!(path.cost, 12);
+        assert_eq!(path.states.len(), 13);
+        assert_eq!(path.states[0], (0, 0));
+        assert_eq!(*path.goal(), end);
+
+        // Every move is between neighbours.
+        for pair in path.states.windows(2) {
+            assert_eq!(Point::from(pair[0]).manhattan(Point::from(pair[1])), 1);
+        }
+
+        assert_eq!(bfs((0, 0), |&p| open(&grid, p), |&p| p == (0, 2)), None);
+    }
+
+    #[test]
+    fn test_distances() {
+        let grid = Grid::from(MAZE);
+
+        let result = distances((0, 0), |&p| open(&grid, p));
+
+        assert_eq!(result[&(0, 0)], 0);
+        assert_eq!(result[&(0, 6)], 12);
+        assert_eq!(result.len(), grid.find_all(&'.').count());
+
+        let past = Instant::now() - Duration::from_millis(1);
+        let cancelled = with_deadline(Some(past), || distances((0, 0), |&p| open(&grid, p)));
+        assert_eq!(cancelled.len(), 1);
+    }
+
+    #[test]
+    fn test_dijkstra() {
+        // A direct edge costs more than going round through b and c.
+        let edges = |node: &char| match node {
+            'a' => vec![('b', 1), ('d', 10)],
+            'b' => vec![('c', 2)],
+            'c' => vec![('d', 3)],
+            _ => vec![],
+        };
+
+        let expected = Path {
+            cost: 6,
+            states: vec!['a', 'b', 'c', 'd'],
+        };
+
+        assert_eq!(dijkstra('a', edges, |&n| n == 'd'), Some(expected.clone()));
+        assert_eq!(dijkstra_buckets('a', edges, |&n| n == 'd'), Some(expected));
+        assert_eq!(dijkstra('d', edges, |&n| n == 'a'), None);
+    }
+
+    #[test]
+    fn test_astar() {
+        // Moving onto a digit costs that digit.
+        let grid = Grid::parse("1163\n1381\n2136\n3694", |c| {
+            c.to_digit(10).unwrap() as usize
+        });
+        let end = (3, 3);
+
+        let successors = |&p: &Position| {
+            grid.neighbours4(p)
+                .map(|next| (next, grid[next]))
+                .collect::<Vec<_>>()
+        };
+        let heuristic = |&p: &Position| Point::from(p).manhattan(Point::from(end));
+
+        let expected = dijkstra((0, 0), successors, |&p| p == end).unwrap();
+        assert_eq!(expected.cost, 17);
+
+        let heap = astar((0, 0), successors, heuristic, |&p| p == end).unwrap();
+        let buckets = astar_buckets((0, 0), successors, heuristic, |&p| p == end).unwrap();
+
+        assert_eq!(heap.cost, expected.cost);
+        assert_eq!(buckets.cost, expected.cost);
+        assert_eq!(*buckets.goal(), end);
+    }
+}
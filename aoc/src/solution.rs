@@ -0,0 +1,215 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    marker::PhantomData,
+    time::{Duration, Instant},
+};
+
+use crate::{alloc::Counter, AllocStats, Answer, CancelToken, Result};
+
+/// A day's puzzle, split into a parse stage and one stage per part so that
+/// the runner can time each of them separately.
+pub trait Solution {
+    type Input;
+
+    /// Skip the runner's input normalisation, for days that need the input
+    /// exactly as it is on disk.
+    const RAW_INPUT: bool = false;
+
+    fn parse(input: &str) -> Self::Input;
+
+    fn part1(input: &Self::Input) -> Option<Answer>;
+
+    fn part2(input: &Self::Input) -> Option<Answer>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stage {
+    Parse,
+    Part1,
+    Part2,
+    /// Both parts solved together by a plain `fn(&str) -> aoc::Result`.
+    Solve,
+}
+
+impl Stage {
+    /// Short machine-readable name, used as a field name in structured output.
+    pub fn key(&self) -> &'static str {
+        match self {
+            Stage::Parse => "parse",
+            Stage::Part1 => "part1",
+            Stage::Part2 => "part2",
+            Stage::Solve => "solve",
+        }
+    }
+}
+
+impl Display for Stage {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Stage::Parse => "Parse",
+            Stage::Part1 => "Part 1",
+            Stage::Part2 => "Part 2",
+            Stage::Solve => "Solve",
+        };
+
+        write!(f, "{}", name)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Report {
+    pub part_1: Option<Answer>,
+    pub part_2: Option<Answer>,
+    pub timings: Vec<(Stage, Duration)>,
+    /// Allocations per stage, only counted under [`CountingAllocator`](crate::CountingAllocator).
+    pub allocations: Vec<(Stage, AllocStats)>,
+}
+
+impl Report {
+    pub fn total_time(&self) -> Duration {
+        self.timings.iter().map(|(_, time)| *time).sum()
+    }
+}
+
+/// Object safe view of a day's solver, as stored in the [`Registry`](crate::Registry).
+/// Shared between threads, so that days can be run in the background.
+pub trait Solver: Send + Sync {
+    fn solve(&self, input: &str) -> Report;
+
+    /// Whether the input is handed over as it is, without normalising it.
+    fn raw_input(&self) -> bool {
+        false
+    }
+}
+
+/// Times each stage, and counts its allocations when possible.
+#[derive(Default)]
+struct Recorder {
+    timings: Vec<(Stage, Duration)>,
+    allocations: Vec<(Stage, AllocStats)>,
+}
+
+impl Recorder {
+    fn stage<T>(&mut self, stage: Stage, f: impl FnOnce() -> T) -> T {
+        let counter = Counter::start();
+        let timer = Instant::now();
+        let result = f();
+        self.timings.push((stage, timer.elapsed()));
+
+        if let Some(counter) = counter {
+            self.allocations.push((stage, counter.finish()));
+        }
+
+        return result;
+    }
+
+    fn report(self, part_1: Option<Answer>, part_2: Option<Answer>) -> Report {
+        return Report {
+            part_1,
+            part_2,
+            timings: self.timings,
+            allocations: self.allocations,
+        };
+    }
+}
+
+impl Solver for fn(&str) -> Result {
+    fn solve(&self, input: &str) -> Report {
+        let mut recorder = Recorder::default();
+
+        let (part_1, part_2) = recorder.stage(Stage::Solve, || self(input));
+
+        return recorder.report(part_1, part_2);
+    }
+}
+
+/// A plain function given its input exactly as it is on disk.
+pub(crate) struct Raw(pub(crate) fn(&str) -> Result);
+
+impl Solver for Raw {
+    fn solve(&self, input: &str) -> Report {
+        self.0.solve(input)
+    }
+
+    fn raw_input(&self) -> bool {
+        true
+    }
+}
+
+pub(crate) struct Staged<S>(PhantomData<fn() -> S>);
+
+impl<S> Staged<S> {
+    pub(crate) fn new() -> Self {
+        return Self(PhantomData);
+    }
+}
+
+impl<S: Solution> Solver for Staged<S> {
+    fn solve(&self, input: &str) -> Report {
+        let mut recorder = Recorder::default();
+        let cancel = CancelToken::current();
+
+        // Later stages are skipped once the time budget has run out.
+        let parsed = recorder.stage(Stage::Parse, || S::parse(input));
+        let part_1 = match cancel.is_cancelled() {
+            true => None,
+            false => recorder.stage(Stage::Part1, || S::part1(&parsed)),
+        };
+        let part_2 = match cancel.is_cancelled() {
+            true => None,
+            false => recorder.stage(Stage::Part2, || S::part2(&parsed)),
+        };
+
+        return recorder.report(part_1, part_2);
+    }
+
+    fn raw_input(&self) -> bool {
+        S::RAW_INPUT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Solution, Solver, Stage, Staged};
+    use crate::Answer;
+
+    struct Lines;
+
+    impl Solution for Lines {
+        type Input = Vec<u64>;
+
+        fn parse(input: &str) -> Self::Input {
+            input.lines().map(|l| l.parse().unwrap()).collect()
+        }
+
+        fn part1(input: &Self::Input) -> Option<Answer> {
+            Some(input.iter().sum::<u64>().into())
+        }
+
+        fn part2(input: &Self::Input) -> Option<Answer> {
+            input.iter().max().copied().map(Answer::from)
+        }
+    }
+
+    #[test]
+    fn test_staged_report() {
+        let report = Staged::<Lines>::new().solve("1\n5\n3");
+
+        assert_eq!(report.part_1, Some(9.into()));
+        assert_eq!(report.part_2, Some(5.into()));
+
+        let stages: Vec<Stage> = report.timings.iter().map(|(s, _)| *s).collect();
+        assert_eq!(stages, vec![Stage::Parse, Stage::Part1, Stage::Part2]);
+    }
+
+    #[test]
+    fn test_function_report() {
+        let solver: fn(&str) -> crate::Result = |input| (Some(input.len().into()), None);
+        let report = solver.solve("abc");
+
+        assert_eq!(report.part_1, Some(3.into()));
+        assert_eq!(report.part_2, None);
+        assert_eq!(report.timings.len(), 1);
+        assert_eq!(report.timings[0].0, Stage::Solve);
+    }
+}
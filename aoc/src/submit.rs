@@ -0,0 +1,501 @@
+use std::{
+    fmt::{self, Display, Formatter},
+    fs, io,
+    path::{Path, PathBuf},
+    time::Duration,
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::fetch::USER_AGENT;
+
+/// Every submission for a day, kept next to its cached input.
+pub fn history_path(cache_dir: &Path, year: u16, day: u8) -> PathBuf {
+    cache_dir
+        .join(year.to_string())
+        .join(format!("day{}", day))
+        .join("submissions.toml")
+}
+
+/// What the server made of a submitted answer.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Correct,
+    /// Wrong, with a cooldown before the next attempt.
+    Wrong(Hint, Duration),
+    /// Submitted too soon after the previous attempt; nothing was checked.
+    Wait(Duration),
+    /// This part has already been solved.
+    AlreadySolved,
+    /// A response that could not be understood, as plain text.
+    Unknown(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Hint {
+    TooHigh,
+    TooLow,
+    None,
+}
+
+impl Display for Outcome {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Outcome::Correct => write!(f, "That's the right answer!"),
+            Outcome::Wrong(Hint::TooHigh, _) => write!(f, "Wrong, the answer is too high"),
+            Outcome::Wrong(Hint::TooLow, _) => write!(f, "Wrong, the answer is too low"),
+            Outcome::Wrong(Hint::None, _) => write!(f, "Wrong answer"),
+            Outcome::Wait(left) => write!(
+                f,
+                "Submitted too recently, {}s left to wait",
+                left.as_secs()
+            ),
+            Outcome::AlreadySolved => write!(f, "This part has already been solved"),
+            Outcome::Unknown(text) => write!(f, "Unrecognised response: {}", text),
+        }
+    }
+}
+
+/// Removes HTML tags and collapses whitespace, leaving the message text.
+fn text(html: &str) -> String {
+    let html = match (html.find("<article"), html.find("</article>")) {
+        (Some(start), Some(end)) if start < end => &html[start..end],
+        _ => html,
+    };
+
+    let mut text = String::new();
+    let mut in_tag = false;
+    for c in html.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+
+    return text.split_whitespace().collect::<Vec<&str>>().join(" ");
+}
+
+/// Parses "1m 41s" or "41s".
+fn parse_wait(s: &str) -> Option<Duration> {
+    s.split_whitespace()
+        .try_fold(Duration::ZERO, |total, part| {
+            let (value, unit) = part.split_at(part.find(|c: char| !c.is_ascii_digit())?);
+            let value: u64 = value.parse().ok()?;
+
+            match unit {
+                "h" => Some(total + Duration::from_secs(value * 3600)),
+                "m" => Some(total + Duration::from_secs(value * 60)),
+                "s" => Some(total + Duration::from_secs(value)),
+                _ => None,
+            }
+        })
+}
+
+/// The cooldown stated after a wrong answer, e.g. "please wait one minute"
+/// or "please wait 5 minutes".
+fn parse_cooldown(text: &str) -> Duration {
+    let minutes = text
+        .to_lowercase()
+        .split_once("please wait ")
+        .and_then(|(_, rest)| rest.split_whitespace().next())
+        .and_then(|amount| match amount {
+            "one" => Some(1),
+            n => n.parse().ok(),
+        });
+
+    return Duration::from_secs(minutes.unwrap_or(1) * 60);
+}
+
+pub fn parse_response(html: &str) -> Outcome {
+    let text = text(html);
+
+    if text.contains("That's the right answer") {
+        return Outcome::Correct;
+    }
+
+    if text.contains("That's not the right answer") {
+        let hint = match (text.contains("too high"), text.contains("too low")) {
+            (true, _) => Hint::TooHigh,
+            (_, true) => Hint::TooLow,
+            _ => Hint::None,
+        };
+
+        return Outcome::Wrong(hint, parse_cooldown(&text));
+    }
+
+    if text.contains("You gave an answer too recently") {
+        let left = text
+            .split_once("You have ")
+            .and_then(|(_, rest)| rest.split_once(" left to wait"))
+            .and_then(|(wait, _)| parse_wait(wait))
+            .unwrap_or(Duration::from_secs(60));
+
+        return Outcome::Wait(left);
+    }
+
+    if text.contains("You don't seem to be solving the right level") {
+        return Outcome::AlreadySolved;
+    }
+
+    return Outcome::Unknown(text);
+}
+
+/// How an attempt was judged, as kept in the history.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "outcome", rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    Wrong { hint: Hint },
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct Attempt {
+    part: u8,
+    answer: String,
+    /// Seconds since the Unix epoch.
+    at: u64,
+    #[serde(flatten)]
+    verdict: Verdict,
+}
+
+/// Past submissions for one day, and when the next one is allowed.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct History {
+    #[serde(default)]
+    next_allowed: u64,
+    #[serde(default, rename = "attempt")]
+    attempts: Vec<Attempt>,
+}
+
+impl History {
+    /// Loads the history file, treating a missing file as no submissions.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match fs::read_to_string(path) {
+            Ok(contents) => {
+                toml::from_str(&contents).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(e),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let contents =
+            toml::to_string(self).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        fs::write(path, contents)
+    }
+
+    /// Why `answer` should not be submitted at `now`, if it shouldn't.
+    fn refuse(&self, part: u8, answer: &str, now: u64) -> Option<SubmitError> {
+        let attempts = self.attempts.iter().filter(|a| a.part == part);
+
+        for attempt in attempts {
+            let bound = match (answer.parse::<i128>(), attempt.answer.parse::<i128>()) {
+                (Ok(answer), Ok(wrong)) => Some((answer, wrong)),
+                _ => None,
+            };
+
+            let known_wrong = match (&attempt.verdict, bound) {
+                (Verdict::Correct, _) => return Some(SubmitError::Solved(attempt.answer.clone())),
+                (
+                    Verdict::Wrong {
+                        hint: Hint::TooHigh,
+                    },
+                    Some((answer, high)),
+                ) => answer >= high,
+                (Verdict::Wrong { hint: Hint::TooLow }, Some((answer, low))) => answer <= low,
+                (Verdict::Wrong { .. }, _) => attempt.answer == answer,
+            };
+
+            if known_wrong {
+                return Some(SubmitError::KnownWrong(
+                    attempt.answer.clone(),
+                    attempt.verdict.clone(),
+                ));
+            }
+        }
+
+        if now < self.next_allowed {
+            return Some(SubmitError::Cooldown(Duration::from_secs(
+                self.next_allowed - now,
+            )));
+        }
+
+        return None;
+    }
+
+    fn record(&mut self, part: u8, answer: &str, outcome: &Outcome, now: u64) {
+        let verdict = match outcome {
+            Outcome::Correct => Verdict::Correct,
+            Outcome::Wrong(hint, _) => Verdict::Wrong { hint: *hint },
+            Outcome::Wait(left) => {
+                self.next_allowed = now + left.as_secs();
+                return;
+            }
+            Outcome::AlreadySolved | Outcome::Unknown(_) => return,
+        };
+
+        if let Outcome::Wrong(_, cooldown) = outcome {
+            self.next_allowed = now + cooldown.as_secs();
+        }
+
+        self.attempts.push(Attempt {
+            part,
+            answer: answer.to_string(),
+            at: now,
+            verdict,
+        });
+    }
+}
+
+#[derive(Debug)]
+pub enum SubmitError {
+    MissingSession,
+    /// The part was already answered correctly, with the answer held here, so
+    /// no further answer for it is submitted.
+    Solved(String),
+    /// Rules the answer out based on an earlier wrong attempt.
+    KnownWrong(String, Verdict),
+    Cooldown(Duration),
+    Status(u16, String),
+    Transport(String),
+    Io(PathBuf, io::Error),
+}
+
+impl Display for SubmitError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            SubmitError::MissingSession => {
+                write!(f, "No session token, set AOC_SESSION or pass --session")
+            }
+            SubmitError::Solved(answer) => write!(f, "Already solved with {}", answer),
+            SubmitError::KnownWrong(
+                previous,
+                Verdict::Wrong {
+                    hint: Hint::TooHigh,
+                },
+            ) => {
+                write!(f, "Not submitting, {} was already too high", previous)
+            }
+            SubmitError::KnownWrong(previous, Verdict::Wrong { hint: Hint::TooLow }) => {
+                write!(f, "Not submitting, {} was already too low", previous)
+            }
+            SubmitError::KnownWrong(previous, _) => {
+                write!(f, "Not submitting, {} was already wrong", previous)
+            }
+            SubmitError::Cooldown(left) => {
+                write!(f, "Still cooling down, try again in {}s", left.as_secs())
+            }
+            SubmitError::Status(code, body) => {
+                write!(f, "Server responded with {}: {}", code, text(body))
+            }
+            SubmitError::Transport(error) => write!(f, "Request failed: {}", error),
+            SubmitError::Io(path, error) => {
+                write!(f, "Could not access {}: {}", path.display(), error)
+            }
+        }
+    }
+}
+
+/// Posts answers, checking and updating the day's history first.
+#[derive(Debug, Clone)]
+pub struct Submitter {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub cache_dir: PathBuf,
+}
+
+impl Submitter {
+    /// Submits `answer`, with `now` in seconds since the Unix epoch.
+    pub fn submit(
+        &self,
+        year: u16,
+        day: u8,
+        part: u8,
+        answer: &str,
+        now: u64,
+    ) -> Result<Outcome, SubmitError> {
+        let path = history_path(&self.cache_dir, year, day);
+        let to_error = |e| SubmitError::Io(path.clone(), e);
+
+        let mut history = History::load(&path).map_err(to_error)?;
+
+        if let Some(refusal) = history.refuse(part, answer, now) {
+            return Err(refusal);
+        }
+
+        let session = self.session.as_ref().ok_or(SubmitError::MissingSession)?;
+        let url = format!(
+            "{}/{}/day/{}/answer",
+            self.base_url.trim_end_matches('/'),
+            year,
+            day
+        );
+
+        let response = ureq::post(&url)
+            .set("Cookie", &format!("session={}", session))
+            .set("User-Agent", USER_AGENT)
+            .send_form(&[("level", &part.to_string()), ("answer", answer)]);
+
+        let body = match response {
+            Ok(response) => response.into_string(),
+            Err(ureq::Error::Status(code, response)) => {
+                return Err(SubmitError::Status(
+                    code,
+                    response.into_string().unwrap_or_default(),
+                ))
+            }
+            Err(error) => return Err(SubmitError::Transport(error.to_string())),
+        };
+        let body = body.map_err(|e| SubmitError::Transport(e.to_string()))?;
+
+        let outcome = parse_response(&body);
+
+        history.record(part, answer, &outcome, now);
+        history.save(&path).map_err(to_error)?;
+
+        return Ok(outcome);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs,
+        io::{BufRead, BufReader, Read, Write},
+        net::TcpListener,
+        sync::mpsc,
+        thread,
+        time::Duration,
+    };
+
+    use super::{parse_response, Hint, Outcome, SubmitError, Submitter};
+
+    fn article(message: &str) -> String {
+        format!(
+            "<html><main><article><p>{}</p></article></main></html>",
+            message
+        )
+    }
+
+    #[test]
+    fn test_parse_responses() {
+        let right = article("That's the right answer! You are <em>one gold star</em> closer.");
+        assert_eq!(parse_response(&right), Outcome::Correct);
+
+        let high = article(
+            "That's not the right answer; your answer is too high. Please wait one minute \
+             before trying again. <a href=\"/2023/day/5\">[Return to Day 5]</a>",
+        );
+        assert_eq!(
+            parse_response(&high),
+            Outcome::Wrong(Hint::TooHigh, Duration::from_secs(60))
+        );
+
+        let wrong =
+            article("That's not the right answer. Please wait 5 minutes before trying again.");
+        assert_eq!(
+            parse_response(&wrong),
+            Outcome::Wrong(Hint::None, Duration::from_secs(300))
+        );
+
+        let wait = article(
+            "You gave an answer too recently; you have to wait after submitting an answer \
+             before trying again.  You have 1m 41s left to wait.",
+        );
+        assert_eq!(
+            parse_response(&wait),
+            Outcome::Wait(Duration::from_secs(101))
+        );
+
+        let solved =
+            article("You don't seem to be solving the right level.  Did you already complete it?");
+        assert_eq!(parse_response(&solved), Outcome::AlreadySolved);
+    }
+
+    /// Answers each request with the next response, sending back the
+    /// request bodies it received.
+    fn stub_server(responses: Vec<String>) -> (String, mpsc::Receiver<String>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let (sender, receiver) = mpsc::channel();
+
+        thread::spawn(move || {
+            for (stream, body) in listener.incoming().zip(responses) {
+                let mut stream = stream.unwrap();
+                let mut reader = BufReader::new(&stream);
+
+                let headers: Vec<String> = reader
+                    .by_ref()
+                    .lines()
+                    .map(|l| l.unwrap())
+                    .take_while(|l| !l.is_empty())
+                    .collect();
+                let length: usize = headers
+                    .iter()
+                    .find_map(|h| {
+                        h.to_ascii_lowercase()
+                            .strip_prefix("content-length: ")
+                            .map(|l| l.parse().unwrap())
+                    })
+                    .unwrap_or(0);
+
+                let mut request = vec![0; length];
+                reader.read_exact(&mut request).unwrap();
+                sender.send(String::from_utf8(request).unwrap()).unwrap();
+
+                let _ = write!(
+                    stream,
+                    "HTTP/1.1 200 OK\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    body.len(),
+                    body
+                );
+            }
+        });
+
+        return (url, receiver);
+    }
+
+    #[test]
+    fn test_submit_records_history() {
+        let (url, requests) = stub_server(vec![
+            article("That's not the right answer; your answer is too low. Please wait one minute before trying again."),
+            article("That's the right answer!"),
+        ]);
+
+        let cache_dir = std::env::temp_dir().join(format!("aoc-submit-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&cache_dir);
+        let submitter = Submitter {
+            base_url: url,
+            session: Some("secret".to_string()),
+            cache_dir,
+        };
+
+        let outcome = submitter.submit(2023, 6, 1, "100", 1000).unwrap();
+        assert_eq!(
+            outcome,
+            Outcome::Wrong(Hint::TooLow, Duration::from_secs(60))
+        );
+        assert_eq!(requests.recv().unwrap(), "level=1&answer=100");
+
+        let too_low = submitter.submit(2023, 6, 1, "90", 2000);
+        assert!(matches!(too_low, Err(SubmitError::KnownWrong(answer, _)) if answer == "100"));
+
+        let cooldown = submitter.submit(2023, 6, 1, "288", 1030);
+        assert!(matches!(cooldown, Err(SubmitError::Cooldown(left)) if left.as_secs() == 30));
+
+        let outcome = submitter.submit(2023, 6, 1, "288", 1060).unwrap();
+        assert_eq!(outcome, Outcome::Correct);
+        assert_eq!(requests.recv().unwrap(), "level=1&answer=288");
+
+        let solved = submitter.submit(2023, 6, 1, "288", 2000);
+        assert!(matches!(solved, Err(SubmitError::Solved(answer)) if answer == "288"));
+    }
+}
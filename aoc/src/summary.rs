@@ -0,0 +1,244 @@
+use std::{cmp::Reverse, time::Duration};
+
+use crate::{runner::DayResult, Day, Stage, Status};
+
+/// How many of the slowest days to highlight.
+const SLOWEST: usize = 3;
+
+const HEADERS: [&str; 10] = [
+    "", "Day", "Answer 1", "Answer 2", "Parse", "Part 1", "Part 2", "Solve", "Total", "Status",
+];
+
+/// Columns holding times, which are right-aligned.
+const TIME_COLUMNS: [usize; 5] = [4, 5, 6, 7, 8];
+
+#[derive(Debug, Clone)]
+enum Row {
+    Solved(Box<DayResult>),
+    /// A day that could not be run, e.g. because its input is missing.
+    Error(Day),
+}
+
+/// Collects every day of a run into one aligned table.
+#[derive(Debug, Clone, Default)]
+pub struct Summary {
+    rows: Vec<Row>,
+}
+
+fn label(day: Day, input: &str) -> String {
+    match input {
+        "input" => day.to_string(),
+        name => format!("{} ({})", day, name),
+    }
+}
+
+fn stage_time(result: &DayResult, stage: Stage) -> String {
+    result
+        .timings
+        .iter()
+        .find(|(s, _)| *s == stage)
+        .map_or("-".to_string(), |(_, time)| format!("{:.2?}", time))
+}
+
+/// `FAIL` if any part failed, then `TIMEOUT`, `NEW`, `PASS`; `-` with nothing to check.
+pub(crate) fn status(result: &DayResult) -> String {
+    let statuses: Vec<Status> = result.parts.iter().filter_map(|p| p.status).collect();
+
+    [Status::Fail, Status::Timeout, Status::New, Status::Pass]
+        .into_iter()
+        .find(|s| statuses.contains(s))
+        .map_or("-".to_string(), |s| s.to_string())
+}
+
+fn cells(row: &Row) -> [String; 10] {
+    match row {
+        Row::Solved(result) => {
+            let [part_1, part_2] = result.parts.each_ref().map(|p| match &p.answer {
+                Some(answer) => answer.to_string(),
+                None => "-".to_string(),
+            });
+
+            [
+                String::new(),
+                label(result.day, &result.input),
+                part_1,
+                part_2,
+                stage_time(result, Stage::Parse),
+                stage_time(result, Stage::Part1),
+                stage_time(result, Stage::Part2),
+                stage_time(result, Stage::Solve),
+                format!("{:.2?}", result.total_time()),
+                status(result),
+            ]
+        }
+        Row::Error(day) => {
+            let mut cells = ["-"; 10].map(String::from);
+            cells[0] = String::new();
+            cells[1] = day.to_string();
+            cells[9] = "ERROR".to_string();
+            cells
+        }
+    }
+}
+
+impl Summary {
+    pub fn push(&mut self, result: DayResult) {
+        self.rows.push(Row::Solved(Box::new(result)));
+    }
+
+    pub fn push_error(&mut self, day: Day) {
+        self.rows.push(Row::Error(day));
+    }
+
+    pub fn total_time(&self) -> Duration {
+        self.results().map(DayResult::total_time).sum()
+    }
+
+    fn results(&self) -> impl Iterator<Item = &DayResult> {
+        self.rows.iter().filter_map(|row| match row {
+            Row::Solved(result) => Some(result.as_ref()),
+            Row::Error(_) => None,
+        })
+    }
+
+    /// Row indices of the slowest days, slowest first.
+    fn slowest(&self) -> Vec<usize> {
+        let mut solved: Vec<(usize, Duration)> = self
+            .rows
+            .iter()
+            .enumerate()
+            .filter_map(|(i, row)| match row {
+                Row::Solved(result) => Some((i, result.total_time())),
+                Row::Error(_) => None,
+            })
+            .collect();
+
+        solved.sort_by_key(|(_, time)| Reverse(*time));
+        solved.truncate(SLOWEST);
+
+        return solved.into_iter().map(|(i, _)| i).collect();
+    }
+
+    /// Renders the table, marking the slowest days with `*`, and in bold
+    /// yellow when `colour` is set.
+    pub fn render(&self, colour: bool) -> String {
+        let slowest = self.slowest();
+
+        let mut rows: Vec<[String; 10]> = self.rows.iter().map(cells).collect();
+        for &i in &slowest {
+            rows[i][0] = "*".to_string();
+        }
+
+        let passed = self.results().filter(|r| status(r) == "PASS").count();
+        let mut total = ["", "Total", "", "", "", "", "", "", "", ""].map(String::from);
+        total[8] = format!("{:.2?}", self.total_time());
+        total[9] = format!("{}/{} PASS", passed, self.rows.len());
+
+        let headers = HEADERS.map(String::from);
+
+        let mut widths = [0; 10];
+        for row in [&headers, &total].into_iter().chain(&rows) {
+            for (width, cell) in widths.iter_mut().zip(row) {
+                *width = (*width).max(cell.chars().count());
+            }
+        }
+
+        let line = |row: &[String; 10]| {
+            row.iter()
+                .enumerate()
+                .map(|(i, cell)| match TIME_COLUMNS.contains(&i) {
+                    true => format!("{:>1$}", cell, widths[i]),
+                    false => format!("{:<1$}", cell, widths[i]),
+                })
+                .collect::<Vec<String>>()
+                .join("  ")
+                .trim_end()
+                .to_string()
+        };
+        let rule = "-".repeat(widths.iter().sum::<usize>() + 2 * (widths.len() - 1));
+
+        let mut lines = vec![line(&headers), rule.clone()];
+        for (i, row) in rows.iter().enumerate() {
+            lines.push(match colour && slowest.contains(&i) {
+                true => format!("\x1b[1;33m{}\x1b[0m", line(row)),
+                false => line(row),
+            });
+        }
+        lines.push(rule);
+        lines.push(line(&total));
+
+        if !slowest.is_empty() {
+            lines.push("* slowest".to_string());
+        }
+
+        return lines.join("\n");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use super::Summary;
+    use crate::{
+        runner::{DayResult, PartResult},
+        Answer, Day, Stage, Status,
+    };
+
+    fn result(day: u8, millis: u64, status: Status) -> DayResult {
+        let part = |part| PartResult {
+            part,
+            answer: Some(Answer::from(day as u64 * 10 + part as u64)),
+            expected: None,
+            status: Some(status),
+        };
+
+        DayResult {
+            day: Day::new(2023, day),
+            input: "input".to_string(),
+            parts: [part(1), part(2)],
+            timings: vec![(Stage::Solve, Duration::from_millis(millis))],
+            allocations: vec![],
+            benchmark: None,
+            recorded: None,
+        }
+    }
+
+    #[test]
+    fn test_render_summary() {
+        let mut summary = Summary::default();
+        summary.push(result(1, 1, Status::Pass));
+        summary.push(result(2, 4, Status::Fail));
+        summary.push_error(Day::new(2023, 3));
+        summary.push(result(4, 3, Status::New));
+        summary.push(result(5, 2, Status::Pass));
+
+        let table = summary.render(false);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(summary.total_time(), Duration::from_millis(10));
+        assert_eq!(
+            lines[0],
+            "   Day     Answer 1  Answer 2  Parse  Part 1  Part 2   Solve    Total  Status"
+        );
+        assert_eq!(
+            lines[2],
+            "   2023/1  11        12            -       -       -  1.00ms   1.00ms  PASS"
+        );
+        assert_eq!(
+            lines[3],
+            "*  2023/2  21        22            -       -       -  4.00ms   4.00ms  FAIL"
+        );
+        assert_eq!(
+            lines[4],
+            "   2023/3  -         -             -       -       -       -        -  ERROR"
+        );
+        assert!(lines[5].starts_with("*  2023/4"));
+        assert!(lines[6].starts_with("*  2023/5"));
+        assert_eq!(
+            lines[8],
+            "   Total                                                      10.00ms  2/5 PASS"
+        );
+        assert_eq!(lines[9], "* slowest");
+    }
+}
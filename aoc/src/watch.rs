@@ -0,0 +1,311 @@
+use std::{
+    collections::BTreeMap,
+    env,
+    ffi::OsString,
+    fs,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+    thread,
+    time::{Duration, SystemTime},
+};
+
+use serde::Deserialize;
+
+use crate::Day;
+
+/// One part's result, read back from the day binary's `--format json` output.
+/// An input that could not be run has just a `message`.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct PartRun {
+    #[serde(default)]
+    input: String,
+    #[serde(default)]
+    part: u8,
+    answer: Option<String>,
+    status: Option<String>,
+    #[serde(default)]
+    timings_ns: BTreeMap<String, u128>,
+    message: Option<String>,
+}
+
+/// Modification time of every file under the watched paths.
+type Snapshot = BTreeMap<PathBuf, SystemTime>;
+
+/// Files a run writes into the day's crate, which must not count as changes
+/// or every rerun would trigger the next.
+const WRITTEN_BY_RUNS: [&str; 2] = ["history.jsonl", "answers.toml"];
+
+fn visit(path: &Path, snapshot: &mut Snapshot) {
+    let written = path
+        .file_name()
+        .is_some_and(|name| WRITTEN_BY_RUNS.iter().any(|w| name == *w));
+    if written {
+        return;
+    }
+
+    match fs::metadata(path) {
+        Ok(meta) if meta.is_dir() => {
+            for entry in fs::read_dir(path).into_iter().flatten().flatten() {
+                visit(&entry.path(), snapshot);
+            }
+        }
+        Ok(meta) => {
+            if let Ok(modified) = meta.modified() {
+                snapshot.insert(path.to_owned(), modified);
+            }
+        }
+        Err(_) => {}
+    }
+}
+
+/// Missing paths are skipped, so a file showing up later counts as a change.
+fn snapshot(paths: &[PathBuf]) -> Snapshot {
+    let mut snapshot = Snapshot::new();
+
+    for path in paths {
+        visit(path, &mut snapshot);
+    }
+
+    return snapshot;
+}
+
+/// The cargo command building and running the day's binary, with `args`
+/// after `--format json`. Reruns are not recorded in the day's history.
+fn command(day: Day, args: &[OsString], release: bool) -> Command {
+    let mut command = Command::new(env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+    command.args(["run", "--quiet", "-p"]).arg(day.package());
+
+    if release {
+        command.arg("--release");
+    }
+
+    command
+        .args(["--", "--format", "json", "--no-history"])
+        .args(args);
+
+    return command;
+}
+
+fn rerun(day: Day, args: &[OsString], release: bool) -> Result<Vec<PartRun>, String> {
+    let output = command(day, args, release)
+        .stderr(Stdio::inherit())
+        .output()
+        .map_err(|e| format!("Could not run cargo: {}", e))?;
+
+    // A day that disagrees with its known answers still exits with a
+    // failure, so only output that isn't JSON counts as a broken build.
+    return serde_json::from_slice(&output.stdout)
+        .map_err(|_| format!("Build or run failed ({})", output.status));
+}
+
+fn answer(run: &PartRun) -> &str {
+    run.answer.as_deref().unwrap_or("-")
+}
+
+fn timing(stage: &str, new: u128, old: Option<u128>) -> String {
+    let new_time = Duration::from_nanos(new as u64);
+
+    match old {
+        Some(old) if old > 0 => {
+            let change = (new as f64 - old as f64) / old as f64 * 100.0;
+            format!(
+                "{} {:?} -> {:?} ({:+.0}%)",
+                stage,
+                Duration::from_nanos(old as u64),
+                new_time,
+                change
+            )
+        }
+        _ => format!("{} {:?}", stage, new_time),
+    }
+}
+
+/// Describes each part of `current`, comparing answers and timings with the
+/// same input and part of `previous`.
+fn diff(previous: &[PartRun], current: &[PartRun]) -> Vec<String> {
+    let mut lines = Vec::new();
+
+    for (idx, run) in current.iter().enumerate() {
+        if let Some(message) = &run.message {
+            lines.push(format!("Error: {}", message));
+            continue;
+        }
+
+        let before = previous
+            .iter()
+            .find(|p| p.input == run.input && p.part == run.part);
+
+        let change = match before {
+            None => String::new(),
+            Some(before) if before.answer == run.answer => " (unchanged)".to_string(),
+            Some(before) => format!(" (was {})", answer(before)),
+        };
+        let status = run
+            .status
+            .as_ref()
+            .map(|s| format!(" [{}]", s))
+            .unwrap_or_default();
+
+        lines.push(format!(
+            "{} Part {}: {}{}{}",
+            run.input,
+            run.part,
+            answer(run),
+            change,
+            status
+        ));
+
+        // Timings are the same for every part of an input, so they follow
+        // its last part.
+        let last = current
+            .get(idx + 1)
+            .is_none_or(|next| next.input != run.input);
+        if last {
+            let before = previous.iter().find(|p| p.input == run.input);
+            let timings: Vec<String> = run
+                .timings_ns
+                .iter()
+                .map(|(stage, &ns)| {
+                    timing(
+                        stage,
+                        ns,
+                        before.and_then(|b| b.timings_ns.get(stage).copied()),
+                    )
+                })
+                .collect();
+
+            lines.push(format!("{} Time Taken: {}", run.input, timings.join(", ")));
+        }
+    }
+
+    return lines;
+}
+
+/// Reruns the day whenever anything under `paths` changes, printing how the
+/// answers and timings moved since the last good run. Runs until killed.
+pub fn watch(day: Day, paths: &[PathBuf], args: &[OsString], release: bool, interval: Duration) {
+    let mut seen = snapshot(paths);
+    let mut previous = Vec::new();
+
+    loop {
+        eprintln!("Day {}: building and running", day);
+
+        match rerun(day, args, release) {
+            Ok(current) => {
+                for line in diff(&previous, &current) {
+                    println!("{}", line);
+                }
+                previous = current;
+            }
+            Err(e) => eprintln!("Day {}: {}", day, e),
+        }
+
+        eprintln!("Day {}: watching for changes, Ctrl-C to stop", day);
+
+        loop {
+            thread::sleep(interval);
+            if snapshot(paths) != seen {
+                break;
+            }
+        }
+
+        // Editors often write a file in several steps, so let it settle.
+        thread::sleep(interval);
+        seen = snapshot(paths);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{collections::BTreeMap, fs};
+
+    use super::{command, diff, snapshot, PartRun};
+    use crate::Day;
+
+    fn part_run(input: &str, part: u8, answer: &str, parse_ns: u128) -> PartRun {
+        PartRun {
+            input: input.to_string(),
+            part,
+            answer: Some(answer.to_string()),
+            status: None,
+            timings_ns: BTreeMap::from([("parse".to_string(), parse_ns)]),
+            message: None,
+        }
+    }
+
+    #[test]
+    fn test_snapshot_changes() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+
+        let paths = [dir.join("src"), dir.join("input.txt")];
+        let before = snapshot(&paths);
+        assert_eq!(before.len(), 1);
+        assert_eq!(snapshot(&paths), before);
+
+        fs::write(dir.join("input.txt"), "1").unwrap();
+        let after = snapshot(&paths);
+        assert_ne!(after, before);
+        assert!(after.contains_key(&dir.join("input.txt")));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_rerun_does_not_retrigger() {
+        let dir = std::env::temp_dir().join(format!("aoc-watch-rerun-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/lib.rs"), "").unwrap();
+
+        let paths = [dir.clone()];
+        let before = snapshot(&paths);
+
+        // What a run with --record and history leaves behind.
+        fs::write(dir.join("history.jsonl"), "{}\n").unwrap();
+        fs::write(dir.join("answers.toml"), "[input]\n").unwrap();
+        assert_eq!(snapshot(&paths), before);
+
+        let command = command(Day::new(2023, 5), &[], false);
+        assert!(command.get_args().any(|arg| arg == "--no-history"));
+
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn test_diff() {
+        let first = [
+            part_run("input", 1, "46", 1000),
+            part_run("input", 2, "51", 1000),
+        ];
+        assert_eq!(
+            diff(&[], &first),
+            [
+                "input Part 1: 46",
+                "input Part 2: 51",
+                "input Time Taken: parse 1µs"
+            ]
+        );
+
+        let second = [
+            part_run("input", 1, "46", 1500),
+            part_run("input", 2, "52", 1500),
+        ];
+        assert_eq!(
+            diff(&first, &second),
+            [
+                "input Part 1: 46 (unchanged)",
+                "input Part 2: 52 (was 51)",
+                "input Time Taken: parse 1µs -> 1.5µs (+50%)"
+            ]
+        );
+
+        let missing: Vec<PartRun> = serde_json::from_str(
+            r#"[{"year":2023,"day":5,"status":"error","message":"Could not read input"}]"#,
+        )
+        .unwrap();
+        assert_eq!(diff(&second, &missing), ["Error: Could not read input"]);
+    }
+}
@@ -0,0 +1,46 @@
+use aoc::{Day, Registry, Solution};
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+
+/// Solves every registered day end to end, as `aoc run` does.
+fn solve(c: &mut Criterion) {
+    let registry = Registry::from(aoc_cli::DAYS);
+    let mut group = c.benchmark_group("solve");
+
+    for (day, solver) in registry.days() {
+        let Some(input) = aoc_bench::input(day, solver.raw_input()) else {
+            continue;
+        };
+
+        group.bench_function(day.package(), |b| {
+            b.iter(|| solver.solve(black_box(&input)))
+        });
+    }
+
+    group.finish();
+}
+
+/// Times the parse and part stages of a day written as a [`Solution`].
+fn stages<S: Solution>(c: &mut Criterion, day: Day) {
+    let Some(input) = aoc_bench::input(day, S::RAW_INPUT) else {
+        return;
+    };
+    let parsed = S::parse(&input);
+    let mut group = c.benchmark_group(day.package());
+
+    group.bench_function("parse", |b| b.iter(|| S::parse(black_box(&input))));
+    group.bench_function("part1", |b| b.iter(|| S::part1(black_box(&parsed))));
+    group.bench_function("part2", |b| b.iter(|| S::part2(black_box(&parsed))));
+    group.finish();
+}
+
+fn solutions(c: &mut Criterion) {
+    stages::<day10::Day10>(c, day10::DAY);
+    stages::<day11::Day11>(c, day11::DAY);
+    stages::<day14::Day14>(c, day14::DAY);
+    stages::<day16::Day16>(c, day16::DAY);
+    stages::<day17::Day17>(c, day17::DAY);
+    stages::<day19::Day19>(c, day19::DAY);
+}
+
+criterion_group!(benches, solve, solutions);
+criterion_main!(benches);
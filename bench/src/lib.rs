@@ -0,0 +1,63 @@
+//! Inputs for the criterion benchmarks in `benches/`, which drive every day
+//! crate through its public API. Run them with `cargo bench -p aoc-bench`.
+
+use std::{fs, path::Path};
+
+use aoc::Day;
+
+/// The day's puzzle input, or its first example when the input is missing,
+/// normalised as `aoc run` would unless the solver asks for `raw` input.
+pub fn input(day: Day, raw: bool) -> Option<String> {
+    // Cargo runs benchmarks from the package directory, while the day's paths
+    // are relative to the workspace root.
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let dir = root.join(day.dir());
+
+    let local = dir.join("src").join("input.txt");
+    let path = match local.exists() {
+        true => local,
+        // A fetched input lives in the cache, which is not under the root.
+        false => root.join(aoc::default_input_path(day)),
+    };
+
+    let contents = fs::read_to_string(path)
+        .ok()
+        .or_else(|| first_example(root, day))?;
+
+    return match raw {
+        true => Some(contents),
+        false => Some(aoc::normalise(&contents)),
+    };
+}
+
+/// The first of the day's examples, in the order `--example` runs them.
+fn first_example(root: &Path, day: Day) -> Option<String> {
+    let sources = aoc::example_sources_in(root, day, None).ok()?;
+    return Some(sources.first()?.read().ok()?.contents);
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env, fs, path::Path};
+
+    use aoc::Day;
+
+    use super::{first_example, input};
+
+    #[test]
+    fn test_input_leaves_current_dir() {
+        let before = env::current_dir().unwrap();
+
+        assert!(input(Day::new(2023, 5), false).is_some());
+        assert_eq!(env::current_dir().unwrap(), before);
+    }
+
+    #[test]
+    fn test_first_example() {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        let day = Day::new(2023, 10);
+        let expected = fs::read_to_string(root.join(day.dir()).join("examples/example.txt"));
+
+        assert_eq!(first_example(root, day), expected.ok());
+    }
+}
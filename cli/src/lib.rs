@@ -0,0 +1,22 @@
+/// Every day crate in the workspace, for the `aoc` binary and the benchmarks.
+pub const DAYS: &[aoc::Register] = &[
+    day1::register,
+    day2::register,
+    day3::register,
+    day4::register,
+    day5::register,
+    day6::register,
+    day7::register,
+    day8::register,
+    day9::register,
+    day10::register,
+    day11::register,
+    day12::register,
+    day13::register,
+    day14::register,
+    day15::register,
+    day16::register,
+    day17::register,
+    day18::register,
+    day19::register,
+];
@@ -0,0 +1,9 @@
+use std::process::ExitCode;
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static ALLOCATOR: aoc::CountingAllocator = aoc::CountingAllocator;
+
+fn main() -> ExitCode {
+    aoc::main(aoc::Registry::from(aoc_cli::DAYS))
+}
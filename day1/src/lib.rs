@@ -0,0 +1,175 @@
+use {once_cell::sync::Lazy, regex::Regex};
+
+/// Sums the calibration values of every line, reading only digits for part 1
+/// and spelled out digits as well for part 2.
+pub fn document_calbration_sum(input: &str) -> aoc::Result {
+    let part_1 = input
+        .lines()
+        .map(get_calibration_value_a)
+        .fold(None, |acc: Option<u64>, val| match val {
+            Some(v) => acc.map(|x| x + v).or(Some(v)),
+            None => acc,
+        });
+
+    let part_2 = input.lines().map(get_calibration_value_b).sum::<u64>();
+
+    return (part_1.map(aoc::Answer::from), Some(part_2.into()));
+}
+
+fn string_to_num(input: &str) -> u64 {
+    return match input {
+        "one" => 1,
+        "two" => 2,
+        "three" => 3,
+        "four" => 4,
+        "five" => 5,
+        "six" => 6,
+        "seven" => 7,
+        "eight" => 8,
+        "nine" => 9,
+        _ => input.parse::<u64>().unwrap(),
+    };
+}
+fn get_calibration_value_a(input: &str) -> Option<u64> {
+    let nums = input.chars().filter(|c| c.is_ascii_digit()).collect::<Vec<_>>();
+
+    match (nums.first(), nums.last()) {
+        (Some(a), Some(b)) => [*a, *b].iter().collect::<String>().parse::<u64>().ok(),
+        _ => None,
+    }
+}
+
+fn get_calibration_value_b(input: &str) -> u64 {
+    static RE_FIRST: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r"(one|two|three|four|five|six|seven|eight|nine|[1-9]).*").unwrap()
+    });
+    static RE_LAST: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r".*(one|two|three|four|five|six|seven|eight|nine|[1-9])").unwrap()
+    });
+
+    let first = RE_FIRST.captures(input).unwrap().get(1).unwrap().as_str();
+    let last = RE_LAST.captures(input).unwrap().get(1).unwrap().as_str();
+
+    let first = string_to_num(first) * 10;
+    let last = string_to_num(last);
+
+    let result = first + last;
+
+    return result;
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 1);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, document_calbration_sum);
+    registry.register_generator(DAY, generate);
+}
+
+/// `size` lines of letters, digits and spelled out digits, each with at least
+/// one digit.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    const WORDS: [&str; 9] = [
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut pieces: Vec<String> = (0..rng.range(1..7))
+            .map(|_| match rng.below(3) {
+                0 => rng.choose(&WORDS).to_string(),
+                1 => rng.range(1..10).to_string(),
+                _ => (0..rng.range(1..5))
+                    .map(|_| (b'a' + rng.below(26) as u8) as char)
+                    .collect(),
+            })
+            .collect();
+
+        let digit = rng.below(pieces.len() + 1);
+        pieces.insert(digit, rng.range(1..10).to_string());
+
+        input += &pieces.concat();
+        input += "\n";
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        document_calbration_sum, generate, get_calibration_value_a, get_calibration_value_b,
+    };
+
+    #[test]
+    fn value_by_line() {
+        let examples = [
+            ("1abc2", 12),
+            ("pqr3stu8vwx", 38),
+            ("a1b2c3d4e5f", 15),
+            ("treb7uchet", 77),
+        ];
+
+        for (input, output) in examples {
+            let result = get_calibration_value_a(input);
+            assert_eq!(result, Some(output));
+        }
+    }
+
+    #[test]
+    fn document_sum_value() {
+        let example = aoc::example!("example");
+        let (result, _) = document_calbration_sum(example);
+        assert_eq!(result, Some(142.into()));
+    }
+
+    #[test]
+    fn value_by_line_part_two() {
+        let examples = [
+            ("1abc2", 12),
+            ("pqr3stu8vwx", 38),
+            ("a1b2c3d4e5f", 15),
+            ("treb7uchet", 77),
+            ("two1nine", 29),
+            ("eightwothree", 83),
+            ("abcone2threexyz", 13),
+            ("xtwone3four", 24),
+            ("4nineeightseven2", 42),
+            ("zoneight234", 14),
+            ("7pqrstsixteen", 76),
+        ];
+
+        for (input, output) in examples {
+            let result = get_calibration_value_b(input);
+            assert_eq!(result, output);
+        }
+    }
+
+    #[test]
+    fn document_sum_value_part_two() {
+        let example = aoc::example!("example2");
+        let (_, result) = document_calbration_sum(example);
+        assert_eq!(result, Some(281.into()));
+    }
+
+    #[test]
+    fn value_by_line_overlapping() {
+        let examples = [("abdoneightabd", 18), ("2abdoneightabd", 28)];
+
+        for (input, output) in examples {
+            let result = get_calibration_value_b(input);
+            assert_eq!(result, output);
+        }
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 50);
+            let (part_1, part_2) = document_calbration_sum(&input);
+
+            assert_eq!(input.lines().count(), 50);
+            assert!(part_1.is_some() && part_2.is_some());
+        }
+    }
+}
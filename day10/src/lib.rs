@@ -0,0 +1,334 @@
+use std::collections::{HashMap, HashSet};
+
+use aoc::{Direction, Grid, Point, Position};
+
+struct Pipe {
+    directions: HashMap<Direction, Direction>,
+}
+
+impl Pipe {
+    fn from_char(value: &char) -> Option<Self> {
+        match value {
+            '-' => Some(Self {
+                directions: HashMap::from([
+                    (Direction::East, Direction::East),
+                    (Direction::West, Direction::West),
+                ]),
+            }),
+            '|' => Some(Self {
+                directions: HashMap::from([
+                    (Direction::North, Direction::North),
+                    (Direction::South, Direction::South),
+                ]),
+            }),
+            'J' => Some(Self {
+                directions: HashMap::from([
+                    (Direction::East, Direction::North),
+                    (Direction::South, Direction::West),
+                ]),
+            }),
+            '7' => Some(Self {
+                directions: HashMap::from([
+                    (Direction::East, Direction::South),
+                    (Direction::North, Direction::West),
+                ]),
+            }),
+            'L' => Some(Self {
+                directions: HashMap::from([
+                    (Direction::South, Direction::East),
+                    (Direction::West, Direction::North),
+                ]),
+            }),
+            'F' => Some(Self {
+                directions: HashMap::from([
+                    (Direction::North, Direction::East),
+                    (Direction::West, Direction::South),
+                ]),
+            }),
+            _ => None,
+        }
+    }
+
+    fn next_direction(&self, direction: &Direction) -> Option<Direction> {
+        self.directions.get(direction).copied()
+    }
+}
+
+/// The field of pipes with the animal's starting tile.
+pub struct Map {
+    map: Grid<char>,
+    start: Position,
+}
+
+impl Map {
+    fn next_tile(
+        &self,
+        current: &Position,
+        direction: &Direction,
+    ) -> Option<(Position, Direction)> {
+        let next_location = self.map.step(*current, *direction)?;
+
+        let next_direction =
+            Pipe::from_char(&self.map[next_location])?.next_direction(direction)?;
+
+        return Some((next_location, next_direction));
+    }
+
+    fn find_start_tile_type(&self) -> char {
+        let dir: Vec<&Direction> = [
+            Direction::North,
+            Direction::South,
+            Direction::East,
+            Direction::West,
+        ]
+        .iter()
+        .filter(|d| self.next_tile(&self.start, d).is_some())
+        .collect();
+
+        match (dir[0], dir[1]) {
+            (Direction::North, Direction::East) => 'L',
+            (Direction::South, Direction::East) => 'F',
+            (Direction::South, Direction::West) => '7',
+            (Direction::North, Direction::West) => 'J',
+            (Direction::East, Direction::West) => '-',
+            _ => '|',
+        }
+    }
+
+    /// The tiles joined to `position` by its pipe. The start joins whichever
+    /// of its neighbours lead into it.
+    fn connected(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+        let exits = match self.map[position] {
+            'S' => Direction::ALL.to_vec(),
+            tile => Pipe::from_char(&tile).map_or(vec![], |p| p.directions.into_values().collect()),
+        };
+
+        exits
+            .into_iter()
+            .filter_map(move |direction| self.next_tile(&position, &direction))
+            .map(|(next, _)| next)
+    }
+
+    /// Every tile of the loop, with the fewest steps along it from the start.
+    fn find_path(&self) -> HashMap<Position, usize> {
+        return aoc::search::distances(self.start, |&position| self.connected(position));
+    }
+
+    /// Steps along the loop to the tile farthest from the start.
+    pub fn find_farthest_point(&self) -> Option<u64> {
+        let path = self.find_path();
+        return path.into_values().max().map(|steps| steps as u64);
+    }
+
+    /// Tiles enclosed by the loop.
+    pub fn find_path_area(&self) -> u64 {
+        let path = self.find_path();
+
+        let counter = self
+            .map
+            .rows()
+            .enumerate()
+            .map(|(row_index, row)| {
+                let (counter, _, _) = row.iter().enumerate().fold(
+                    (0, false, None),
+                    |(counter, inside, last_boundry), (col_index, char)| {
+                        let position = (row_index, col_index);
+                        let is_pipe = path.contains_key(&position);
+
+                        let is_boundry = match (char, last_boundry) {
+                            ('|', _) => true,
+                            ('S', _) => true,
+                            ('F', _) => true,
+                            ('L', _) => true,
+                            ('7', Some('L')) => false,
+                            ('J', Some('F')) => false,
+                            ('7', _) => true,
+                            ('J', _) => true,
+                            _ => false,
+                        };
+
+                        let found_boundry = match char {
+                            'S' => self.find_start_tile_type(),
+                            _ => *char,
+                        };
+
+                        match (is_pipe, is_boundry, inside) {
+                            (true, true, _) => (counter, !inside, Some(found_boundry)),
+                            (true, false, _) => (counter, inside, last_boundry),
+                            (false, _, true) => (counter + 1, inside, None),
+                            _ => (counter, inside, None),
+                        }
+                    },
+                );
+                return counter;
+            })
+            .sum();
+
+        return counter;
+    }
+}
+
+impl From<&str> for Map {
+    fn from(value: &str) -> Self {
+        let map = Grid::from(value);
+
+        let start = map.find(&'S').unwrap();
+
+        return Self { map, start };
+    }
+}
+
+/// Day 10: Pipe Maze.
+pub struct Day10;
+
+impl aoc::Solution for Day10 {
+    type Input = Map;
+
+    fn parse(input: &str) -> Map {
+        Map::from(input)
+    }
+
+    fn part1(map: &Map) -> Option<aoc::Answer> {
+        map.find_farthest_point().map(aoc::Answer::from)
+    }
+
+    fn part2(map: &Map) -> Option<aoc::Answer> {
+        Some(map.find_path_area().into())
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 10);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register_solution::<Day10>(DAY);
+    registry.register_generator(DAY, generate);
+}
+
+/// A `size` by `size` field of junk pipes around a random loop, starting on
+/// one of its `F`, `L` or `|` tiles.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    const JUNK: [char; 7] = ['|', '-', 'L', 'J', '7', 'F', '.'];
+
+    let size = size.max(2);
+    let path = aoc::gen::simple_loop(rng, size - 1, size - 1);
+    let junk = (0..size * size).map(|_| *rng.choose(&JUNK)).collect();
+    let mut grid = Grid::new(size, size, junk);
+
+    let direction = |from: Position, to: Position| {
+        let step = Point::from(to) - Point::from(from);
+        Direction::ALL
+            .into_iter()
+            .find(|d| d.vector() == step)
+            .unwrap()
+    };
+
+    for (i, &tile) in path.iter().enumerate() {
+        let previous = path[(i + path.len() - 1) % path.len()];
+        let next = path[(i + 1) % path.len()];
+
+        let mut ends = [direction(tile, previous), direction(tile, next)];
+        ends.sort();
+
+        grid[tile] = match ends {
+            [Direction::North, Direction::South] => '|',
+            [Direction::East, Direction::West] => '-',
+            [Direction::North, Direction::East] => 'L',
+            [Direction::North, Direction::West] => 'J',
+            [Direction::South, Direction::West] => '7',
+            _ => 'F',
+        };
+    }
+
+    let starts: Vec<Position> = path
+        .iter()
+        .copied()
+        .filter(|&tile| matches!(grid[tile], 'F' | 'L' | '|'))
+        .collect();
+    let start = *rng.choose(&starts);
+    grid[start] = 'S';
+
+    // Junk next to the start must not look connected to it.
+    let on_path: HashSet<Position> = path.into_iter().collect();
+    let junk: Vec<Position> = grid
+        .neighbours4(start)
+        .filter(|tile| !on_path.contains(tile))
+        .collect();
+    for tile in junk {
+        grid[tile] = '.';
+    }
+
+    return grid.to_string() + "\n";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, Map};
+
+    #[test]
+    fn test_find_farthest_point() {
+        let input = aoc::example!("example");
+
+        let result = Map::from(input).find_farthest_point();
+
+        assert_eq!(result, Some(4));
+    }
+
+    #[test]
+    fn test_find_farthest_point_complex() {
+        let input = aoc::example!("example2");
+
+        let result = Map::from(input).find_farthest_point();
+
+        assert_eq!(result, Some(8));
+    }
+
+    #[test]
+    fn find_path_area() {
+        let input = aoc::example!("example3");
+
+        let result = Map::from(input).find_path_area();
+
+        assert_eq!(result, 4);
+    }
+    #[test]
+    fn find_path_area_2() {
+        let input = aoc::example!("example4");
+
+        let result = Map::from(input).find_path_area();
+
+        assert_eq!(result, 8);
+    }
+
+    #[test]
+    fn find_path_area_complex() {
+        let input = aoc::example!("example5");
+
+        let result = Map::from(input).find_path_area();
+
+        assert_eq!(result, 10);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 20);
+            let map = Map::from(input.as_str());
+
+            // The generator's loop, whose enclosed tiles follow from its area
+            // by Pick's theorem.
+            let path = aoc::gen::simple_loop(&mut aoc::gen::Rng::new(seed), 19, 19);
+            let twice_area = path
+                .iter()
+                .zip(path.iter().cycle().skip(1))
+                .map(|(a, b)| (a.0 * b.1) as i64 - (b.0 * a.1) as i64)
+                .sum::<i64>()
+                .abs();
+
+            assert_eq!(map.find_farthest_point(), Some(path.len() as u64 / 2));
+            assert_eq!(
+                map.find_path_area() as i64,
+                (twice_area - path.len() as i64) / 2 + 1
+            );
+        }
+    }
+}
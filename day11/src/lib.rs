@@ -0,0 +1,140 @@
+use std::collections::BTreeSet;
+
+use aoc::{Grid, Point, Position};
+
+/// The galaxies of an image, before the universe expands.
+pub struct Universe {
+    empty_rows: BTreeSet<usize>,
+    empty_cols: BTreeSet<usize>,
+    galaxies: Vec<Position>,
+}
+
+impl From<&str> for Universe {
+    fn from(value: &str) -> Self {
+        let map = Grid::from(value);
+
+        let empty_rows =
+            BTreeSet::from_iter((0..map.height()).filter(|&row| !map.row(row).contains(&'#')));
+
+        let empty_cols = BTreeSet::from_iter(
+            (0..map.width()).filter(|&col| !map.column(col).any(|s| *s == '#')),
+        );
+
+        let galaxies = map.find_all(&'#').collect();
+
+        return Self {
+            empty_cols,
+            empty_rows,
+            galaxies,
+        };
+    }
+}
+
+impl Universe {
+    /// Sums the distances between every pair of galaxies, once each empty row
+    /// and column is `age` times larger.
+    pub fn sum_distances(&self, age: usize) -> u64 {
+        self.galaxies
+            .iter()
+            .enumerate()
+            .map(|(index, loc)| {
+                self.galaxies[index..]
+                    .iter()
+                    .map(|locb| {
+                        let mut row_ends = [loc.0, locb.0];
+                        row_ends.sort();
+
+                        let mut col_ends = [loc.1, locb.1];
+                        col_ends.sort();
+
+                        let row_range = row_ends[0]..row_ends[1];
+                        let col_range = col_ends[0]..col_ends[1];
+
+                        let rows_expansion = self.empty_rows.range(row_range).count() * (age - 1);
+                        let cols_expansion = self.empty_cols.range(col_range).count() * (age - 1);
+
+                        let distance = Point::from(*loc).manhattan(Point::from(*locb));
+
+                        return (distance + rows_expansion + cols_expansion) as u64;
+                    })
+                    .sum::<u64>()
+            })
+            .sum()
+    }
+}
+
+/// Day 11: Cosmic Expansion.
+pub struct Day11;
+
+impl aoc::Solution for Day11 {
+    type Input = Universe;
+
+    fn parse(input: &str) -> Universe {
+        Universe::from(input)
+    }
+
+    fn part1(universe: &Universe) -> Option<aoc::Answer> {
+        Some(universe.sum_distances(2).into())
+    }
+
+    fn part2(universe: &Universe) -> Option<aoc::Answer> {
+        Some(universe.sum_distances(1000000).into())
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 11);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register_solution::<Day11>(DAY);
+    registry.register_generator(DAY, generate);
+}
+
+/// A `size` by `size` image of sparse galaxies, with about a tenth of the
+/// rows and columns left empty.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let empty_rows: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+    let empty_cols: Vec<bool> = (0..size).map(|_| rng.chance(0.1)).collect();
+
+    let mut input = String::new();
+
+    for empty_row in &empty_rows {
+        for empty_col in &empty_cols {
+            let galaxy = !empty_row && !empty_col && rng.chance(0.03);
+            input.push(if galaxy { '#' } else { '.' });
+        }
+
+        input += "\n";
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, Universe};
+
+    #[test]
+    fn test_sum_distances() {
+        let input = aoc::example!("example");
+
+        let result = Universe::from(input).sum_distances(2);
+
+        assert_eq!(result, 374);
+
+        let result = Universe::from(input).sum_distances(100);
+
+        assert_eq!(result, 8410);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 40);
+            let universe = Universe::from(input.as_str());
+
+            // Distances grow linearly with the age of the universe.
+            let (young, old) = (universe.sum_distances(2), universe.sum_distances(3));
+            assert_eq!(universe.sum_distances(4) - old, old - young);
+        }
+    }
+}
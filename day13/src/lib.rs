@@ -0,0 +1,220 @@
+use aoc::Grid;
+
+/// A pattern of ash and rocks, parsed from one block of the input.
+pub struct Pattern {
+    map: Grid<char>,
+    allowed_smudges: u64,
+}
+
+impl From<&str> for Pattern {
+    fn from(value: &str) -> Self {
+        let map = Grid::from(value);
+
+        return Self { map, allowed_smudges: 0 };
+    }
+}
+
+enum Direction {
+    Vertical,
+    Horizontal,
+}
+
+impl Pattern {
+
+    /// Accepts reflections that differ by up to `amount` cells.
+    pub fn allow_smudges(mut self, amount: u64) -> Self{
+        self.allowed_smudges = amount;
+        return self
+    }
+
+    fn is_reflection(&self, search: &Grid<char>, idx: usize) -> bool {
+        let before = (0..idx + 1).rev().map(|row| search.row(row));
+        let after = (idx + 1..search.height()).map(|row| search.row(row));
+
+        let smudges: u64 = before
+            .zip(after)
+            .map(|(a, b)| a.iter().zip(b.iter()).filter(|(x, y)| x != y).count() as u64)
+            .sum();
+
+        return smudges == self.allowed_smudges;
+
+    }
+
+    fn find_reflection(&self, direction: Direction) -> u32 {
+        // Columns are compared as the rows of the transposed pattern.
+        let search = match direction {
+            Direction::Vertical => self.map.transpose(),
+            Direction::Horizontal => self.map.clone(),
+        };
+
+        (0..search.height() - 1)
+            .filter(|c| self.is_reflection(&search, *c))
+            .map(|v| v + 1)
+            .sum::<usize>() as u32
+    }
+
+    /// Columns left of a vertical reflection, or 100 times the rows above a
+    /// horizontal one.
+    pub fn find_reflection_score(&self) -> u32 {
+        let v_score = self.find_reflection(Direction::Vertical);
+        let h_score = self.find_reflection(Direction::Horizontal) * 100;
+        return v_score + h_score;
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 13);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, |input| {
+        let part_1 = input
+            .split("\n\n")
+            .map(|l| Pattern::from(l).find_reflection_score())
+            .sum::<u32>() as u64;
+
+        let part_2 = input
+            .split("\n\n")
+            .map(|l| Pattern::from(l).allow_smudges(1).find_reflection_score())
+            .sum::<u32>() as u64;
+
+        return (Some(part_1.into()), Some(part_2.into()));
+    });
+    registry.register_generator(DAY, generate);
+}
+
+/// Mirror lines of `rows` between rows, whose reflections differ in exactly
+/// `smudges` cells.
+fn mirror_lines(rows: &Grid<bool>, smudges: usize) -> usize {
+    (1..rows.height())
+        .filter(|&i| {
+            (0..i)
+                .rev()
+                .zip(i..rows.height())
+                .map(|(a, b)| (rows.row(a), rows.row(b)))
+                .map(|(a, b)| a.iter().zip(b).filter(|(x, y)| x != y).count())
+                .sum::<usize>()
+                == smudges
+        })
+        .count()
+}
+
+/// `size` patterns of up to 17 by 17, each with exactly one mirror line, and
+/// exactly one other line that is a mirror but for a single smudge.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let mut patterns = Vec::new();
+
+    while patterns.len() < size {
+        let (height, width) = (rng.range(5..18) as usize, rng.range(5..18) as usize);
+        let (mirror, smudged) = (
+            rng.range(1..height as u64) as usize,
+            rng.range(1..height as u64) as usize,
+        );
+
+        let pairs =
+            |line: usize| (0..line.min(height - line)).map(move |i| (line - 1 - i, line + i));
+
+        // Rows that must match either mirror share a class, except for one
+        // pair across the smudged line that will differ in a single cell.
+        let mut smudge_pairs: Vec<(usize, usize)> = pairs(smudged).collect();
+        let smudge = smudge_pairs.swap_remove(rng.below(smudge_pairs.len()));
+
+        let mut class: Vec<usize> = (0..height).collect();
+        let find = |class: &[usize], mut row: usize| {
+            while class[row] != row {
+                row = class[row];
+            }
+            row
+        };
+
+        for (a, b) in pairs(mirror).chain(smudge_pairs) {
+            let (a, b) = (find(&class, a), find(&class, b));
+            class[a] = b;
+        }
+
+        let (a, b) = (find(&class, smudge.0), find(&class, smudge.1));
+        if a == b {
+            continue;
+        }
+
+        let mut values: Vec<Option<Vec<bool>>> = vec![None; height];
+        values[a] = Some((0..width).map(|_| rng.chance(0.5)).collect());
+        values[b] = values[a].clone();
+        values[b].as_mut().unwrap()[rng.below(width)] ^= true;
+
+        let cells: Vec<bool> = (0..height)
+            .flat_map(|row| {
+                let class = find(&class, row);
+                values[class]
+                    .get_or_insert_with(|| (0..width).map(|_| rng.chance(0.5)).collect())
+                    .clone()
+            })
+            .collect();
+        let rows = Grid::new(width, height, cells);
+
+        let rows = match rng.chance(0.5) {
+            true => rows.transpose(),
+            false => rows,
+        };
+
+        // Random rows can happen to add more mirrors, so check the result.
+        let count =
+            |smudges| mirror_lines(&rows, smudges) + mirror_lines(&rows.transpose(), smudges);
+        if count(0) != 1 || count(1) != 1 {
+            continue;
+        }
+
+        let pattern = rows.map(|&rock| if rock { '#' } else { '.' });
+        patterns.push(pattern.to_string());
+    }
+
+    return patterns.join("\n\n") + "\n";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, Pattern};
+
+    fn patterns() -> (&'static str, &'static str) {
+        return aoc::example!("example").split_once("\n\n").unwrap();
+    }
+
+    #[test]
+    fn test_find_reflection_pattern_a() {
+        let input = patterns().0;
+
+        let result = Pattern::from(input).find_reflection_score();
+
+        assert_eq!(result, 5);
+
+        let result = Pattern::from(input).allow_smudges(1).find_reflection_score();
+
+        assert_eq!(result, 300);
+    }
+
+    #[test]
+    fn test_find_reflection_pattern_b() {
+        let input = patterns().1;
+
+        let result = Pattern::from(input).find_reflection_score();
+
+        assert_eq!(result, 400);
+
+        let result = Pattern::from(input).allow_smudges(1).find_reflection_score();
+
+        assert_eq!(result, 100);
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut aoc::gen::Rng::new(13), 50);
+
+        for pattern in input.split("\n\n") {
+            let clean = Pattern::from(pattern).find_reflection_score();
+            let smudged = Pattern::from(pattern)
+                .allow_smudges(1)
+                .find_reflection_score();
+
+            assert!(clean > 0 && smudged > 0);
+            assert_ne!(clean, smudged);
+        }
+    }
+}
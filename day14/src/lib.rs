@@ -0,0 +1,230 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt::{self, Display, Formatter},
+    ops::Mul,
+};
+
+use aoc::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Tile {
+    Rounded,
+    Obsticle,
+    Empty,
+}
+
+impl From<char> for Tile {
+    fn from(value: char) -> Self {
+        match value {
+            'O' => Self::Rounded,
+            '#' => Self::Obsticle,
+            '.' => Self::Empty,
+            _ => panic!("Invalid Char"),
+        }
+    }
+}
+
+impl Display for Tile {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Empty => '.',
+            Tile::Obsticle => '#',
+            Tile::Rounded => 'O',
+        };
+
+        write!(f, "{}", c)
+    }
+}
+
+/// The platform of rounded and cube-shaped rocks.
+#[derive(Clone)]
+pub struct Dish {
+    grid: Grid<Tile>,
+}
+
+impl From<&str> for Dish {
+    fn from(value: &str) -> Self {
+        let grid = Grid::parse(value, Tile::from);
+
+        return Dish { grid };
+    }
+}
+
+impl Display for Dish {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.grid)
+    }
+}
+
+impl Dish {
+    fn tilt(&mut self, rotate: bool) -> &mut Self {
+        for col in 0..self.grid.width() {
+            let mut free_space = None;
+
+            for row in 0..self.grid.height() {
+                match (self.grid[(row, col)], free_space) {
+                    (Tile::Empty, None) => {
+                        free_space = Some(row);
+                    }
+                    (Tile::Obsticle, Some(_)) => {
+                        free_space = None;
+                    }
+                    (Tile::Rounded, Some(free_row)) => {
+                        self.grid[(free_row, col)] = Tile::Rounded;
+                        self.grid[(row, col)] = Tile::Empty;
+                        free_space = Some(free_row + 1);
+                    }
+                    _ => {}
+                };
+            }
+        }
+
+        if rotate {
+            self.grid = self.grid.rotate_clockwise();
+        }
+
+        return self;
+    }
+
+    /// Load on the north support beams.
+    pub fn total_load(&self) -> u64 {
+        self.grid
+            .rows()
+            .rev()
+            .enumerate()
+            .map(|(idx, row)| {
+                row.iter()
+                    .filter(|c| **c == Tile::Rounded)
+                    .count()
+                    .mul(idx + 1) as u64
+            })
+            .sum()
+    }
+
+    fn cycle(&mut self) -> &Self {
+        self.tilt(true).tilt(true).tilt(true).tilt(true);
+
+        return self;
+    }
+
+    fn cycle_repeat(mut self, count: u64) -> Option<Self> {
+        let cancel = aoc::CancelToken::current();
+        let mut seen = HashMap::from([(self.grid.clone(), 0)]);
+
+        let mut cycle_count = 0;
+        let cycle_len;
+
+        loop {
+            if cancel.is_cancelled() {
+                return None;
+            }
+
+            cycle_count += 1;
+            aoc::trace!("cycle {}", cycle_count);
+            self.cycle();
+            match seen.entry(self.grid.clone()) {
+                Entry::Occupied(seen_at) => {
+                    cycle_len = cycle_count - seen_at.get();
+                    aoc::debug!("cycle {} repeats cycle {}", cycle_count, seen_at.get());
+                    break;
+                }
+                Entry::Vacant(entry) => {
+                    entry.insert(cycle_count);
+                }
+            }
+        }
+
+        let remaining_cycles = (count - cycle_count) % cycle_len;
+
+        for _ in 0..remaining_cycles {
+            self.cycle();
+        }
+
+        return Some(self);
+    }
+}
+
+/// Day 14: Parabolic Reflector Dish.
+pub struct Day14;
+
+impl aoc::Solution for Day14 {
+    type Input = Dish;
+
+    fn parse(input: &str) -> Dish {
+        Dish::from(input)
+    }
+
+    fn part1(dish: &Dish) -> Option<aoc::Answer> {
+        Some(dish.clone().tilt(false).total_load().into())
+    }
+
+    fn part2(dish: &Dish) -> Option<aoc::Answer> {
+        dish.clone()
+            .cycle_repeat(1_000_000_000)
+            .map(|dish| dish.total_load().into())
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 14);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register_solution::<Day14>(DAY);
+    registry.register_generator(DAY, generate);
+}
+
+/// A `size` by `size` platform, about a fifth rounded rocks and a tenth
+/// cube-shaped ones.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(match rng.below(10) {
+                0 | 1 => 'O',
+                2 => '#',
+                _ => '.',
+            });
+        }
+
+        input += "\n";
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, Dish};
+
+    const INPUT: &str = aoc::example!("example");
+
+    #[test]
+    fn test_total_load_north() {
+        let result = Dish::from(INPUT).tilt(false).total_load();
+        assert_eq!(result, 136);
+    }
+
+    #[test]
+    fn test_total_load_cycled() {
+        let result = Dish::from(INPUT).cycle_repeat(1_000_000_000).map(|d| d.total_load());
+        assert_eq!(result, Some(64));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 20);
+            let dish = Dish::from(input.as_str());
+
+            // Tilting north never lowers the load, and cycling keeps every rock.
+            let mut tilted = dish.clone();
+            assert!(tilted.tilt(false).total_load() >= dish.total_load());
+
+            let cycled = dish.clone().cycle_repeat(1_000_000_000).unwrap();
+            assert_eq!(
+                cycled.to_string().matches('O').count(),
+                input.matches('O').count()
+            );
+        }
+    }
+}
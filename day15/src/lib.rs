@@ -0,0 +1,159 @@
+use core::panic;
+
+/// The HASH of a single step.
+pub fn hash(input: &str) -> u64 {
+    input.chars().fold(0_u64, |acc, val| {
+        let ascii = (val.to_ascii_lowercase() as u8) as u64;
+        return ((acc + ascii) * 17) % 256;
+    })
+}
+
+/// Sums the HASH of every step in the initialization sequence.
+pub fn sum_hashed(input: &str) -> u64 {
+    input.trim_end().split(",").map(hash).sum()
+}
+
+fn box_focusing_power(idx: usize, lenses: &Vec<(&str, u64)>) -> u64 {
+    return lenses
+        .iter()
+        .enumerate()
+        .map(|(s, (_, f))| (idx as u64 + 1) * (s as u64 + 1) * f)
+        .sum();
+}
+
+/// Runs the initialization sequence and sums the focusing power of the lenses.
+pub fn lens_power(input: &str) -> u64 {
+    let mut boxes: Vec<Vec<(&str, u64)>> = vec![vec![]; 256];
+
+    for x in input.trim_end().split(",") {
+        let split_point = x.chars().position(|c| c == '-' || c == '=').unwrap();
+        let (label, operation) = x.split_at(split_point);
+        let target_box = hash(label) as usize;
+        let mut chars = operation.chars();
+
+        match chars.next() {
+            Some('-') => {
+                boxes[target_box].retain(|(l, _)| *l != label);
+            }
+            Some('=') => {
+                let current_index = boxes[target_box].iter().position(|(l, _)| *l == label);
+
+                boxes[target_box].push((label, chars.next().unwrap().to_digit(10).unwrap() as u64));
+
+                _ = current_index.map(|idx| boxes[target_box].swap_remove(idx))
+            }
+            _ => panic!("Invalid Operation"),
+        }
+    }
+
+    return boxes
+        .iter()
+        .enumerate()
+        .map(|(idx, lenses)| box_focusing_power(idx, lenses))
+        .sum();
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 15);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, |input| {
+        let part_1 = sum_hashed(input);
+        let part_2 = lens_power(input);
+
+        return (Some(part_1.into()), Some(part_2.into()));
+    });
+    registry.register_generator(DAY, generate);
+}
+
+/// `size` steps over a pool of labels, about three in five adding a lens.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let labels: Vec<String> = (0..(size / 4).max(1))
+        .map(|_| {
+            (0..rng.range(2..7))
+                .map(|_| (b'a' + rng.below(26) as u8) as char)
+                .collect()
+        })
+        .collect();
+
+    let steps: Vec<String> = (0..size)
+        .map(|_| {
+            let label = rng.choose(&labels);
+            match rng.chance(0.6) {
+                true => format!("{}={}", label, rng.range(1..10)),
+                false => format!("{}-", label),
+            }
+        })
+        .collect();
+
+    return steps.join(",") + "\n";
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, hash, lens_power, sum_hashed};
+
+    const INPUT: &str = aoc::example!("example");
+
+    #[test]
+    fn test_sum_hashed() {
+        let result = sum_hashed(INPUT);
+        assert_eq!(result, 1320);
+    }
+
+    #[test]
+    fn test_lens_power() {
+        let result = lens_power(INPUT);
+        assert_eq!(result, 145);
+    }
+
+    /// The focusing power from one list of every lens in the order it was
+    /// placed, rather than from the boxes.
+    fn brute_force_lens_power(input: &str) -> u64 {
+        let mut lenses: Vec<(&str, u64)> = vec![];
+
+        for step in input.trim_end().split(",") {
+            match step.split_once("=") {
+                Some((label, focal)) => {
+                    let focal = focal.parse().unwrap();
+                    match lenses.iter_mut().find(|(l, _)| *l == label) {
+                        Some(lens) => lens.1 = focal,
+                        None => lenses.push((label, focal)),
+                    }
+                }
+                None => lenses.retain(|(l, _)| *l != step.trim_end_matches("-")),
+            }
+        }
+
+        return lenses
+            .iter()
+            .enumerate()
+            .map(|(i, (label, focal))| {
+                let target_box = hash(label);
+                let slot = lenses[..i].iter().filter(|(l, _)| hash(l) == target_box).count();
+                (target_box + 1) * (slot as u64 + 1) * focal
+            })
+            .sum();
+    }
+
+    #[test]
+    fn test_generate() {
+        assert_eq!(brute_force_lens_power(INPUT), 145);
+
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 500);
+            let steps: Vec<&str> = input.trim_end().split(",").collect();
+            assert_eq!(steps.len(), 500);
+
+            for step in &steps {
+                let (label, focal) = match step.strip_suffix("-") {
+                    Some(label) => (label, None),
+                    None => step.split_once("=").map(|(l, f)| (l, Some(f))).unwrap(),
+                };
+                assert!(!label.is_empty() && label.chars().all(|c| c.is_ascii_lowercase()));
+                assert!(focal.is_none_or(|f| matches!(f.parse(), Ok(1..=9))));
+            }
+
+            assert_eq!(lens_power(&input), brute_force_lens_power(&input));
+        }
+    }
+}
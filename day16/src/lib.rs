@@ -0,0 +1,285 @@
+use std::{
+    collections::{BTreeMap, HashSet},
+    str::FromStr,
+};
+
+use aoc::{Direction, Grid, Position};
+
+/// Day 16: The Floor Will Be Lava.
+pub struct Day16;
+
+impl aoc::Solution for Day16 {
+    type Input = Contraption;
+
+    fn parse(input: &str) -> Contraption {
+        Contraption::from_str(input).expect("Failed to Parse")
+    }
+
+    fn part1(contraption: &Contraption) -> Option<aoc::Answer> {
+        let tiles = contraption.count_activated_tiles(Photon {
+            position: (0, 0),
+            direction: Direction::East,
+        });
+
+        Some(tiles.into())
+    }
+
+    fn part2(contraption: &Contraption) -> Option<aoc::Answer> {
+        Some(contraption.find_max_configuration().into())
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 16);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register_solution::<Day16>(DAY);
+    registry.register_generator(DAY, generate);
+}
+
+/// A `size` by `size` contraption, with a mirror or splitter on about one
+/// tile in seven.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    const DEVICES: [char; 4] = ['/', '\\', '|', '-'];
+
+    let mut input = String::new();
+
+    for _ in 0..size {
+        for _ in 0..size {
+            input.push(match rng.chance(0.15) {
+                true => *rng.choose(&DEVICES),
+                false => '.',
+            });
+        }
+
+        input += "\n";
+    }
+
+    return input;
+}
+
+/// The mirrors and splitters of the contraption, indexed by row and by column.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Contraption {
+    rows: Vec<BTreeMap<usize, char>>,
+    cols: Vec<BTreeMap<usize, char>>,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub struct ParseContraptionError;
+
+impl FromStr for Contraption {
+    type Err = ParseContraptionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let grid = Grid::from(s);
+
+        if grid.width() == 0 {
+            return Err(ParseContraptionError);
+        }
+
+        let rows = grid.rows().map(|row| devices(row.iter(), '-')).collect();
+        let cols = grid.columns().map(|col| devices(col, '|')).collect();
+
+        return Ok(Self { rows, cols });
+    }
+}
+
+/// The devices along a row or column by index, leaving out the splitter a
+/// beam along it `passes` straight through.
+fn devices<'a>(line: impl Iterator<Item = &'a char>, passes: char) -> BTreeMap<usize, char> {
+    line.enumerate()
+        .filter(|(_, char)| **char != '.' && **char != passes)
+        .map(|(idx, char)| (idx, *char))
+        .collect()
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+struct Photon {
+    position: Position,
+    direction: Direction,
+}
+
+impl Contraption {
+    fn count_activated_tiles(&self, init: Photon) -> u64 {
+        let mut visited_tiles = HashSet::from([init.position]);
+        let mut photon_history = HashSet::new();
+
+        let start_search = match init.direction {
+            Direction::West | Direction::East => self
+                .rows
+                .get(init.position.0)
+                .and_then(|c| c.get(&init.position.1)),
+            Direction::North | Direction::South => self
+                .cols
+                .get(init.position.1)
+                .and_then(|r| r.get(&init.position.0)),
+        };
+
+        let mut photons = match start_search {
+            Some(tile) => self.tile_beam_result(tile, init.position, init.direction),
+            None => vec![init],
+        };
+
+        while !photons.is_empty() {
+            let new_photons = photons.iter().copied().flat_map(|photon| {
+                let (row, col) = photon.position;
+
+                if photon_history.contains(&photon) {
+                    return vec![];
+                }
+
+                let (new_row, new_col, tile) = match photon.direction {
+                    Direction::East => {
+                        let next = self.rows.get(row).unwrap();
+                        next.range(col + 1..)
+                            .next()
+                            .map_or((row, self.cols.len() - 1, '?'), |(col_idx, char)| {
+                                (row, *col_idx, *char)
+                            })
+                    }
+                    Direction::West => {
+                        let next = self.rows.get(row).unwrap();
+                        next.range(..col)
+                            .next_back()
+                            .map_or((row, 0, '?'), |(col_idx, char)| (row, *col_idx, *char))
+                    }
+                    Direction::North => {
+                        let next = self.cols.get(col).unwrap();
+                        next.range(..row)
+                            .next_back()
+                            .map_or((0, col, '?'), |(row_idx, char)| (*row_idx, col, *char))
+                    }
+                    Direction::South => {
+                        let next = self.cols.get(col).unwrap();
+                        next.range(row + 1..)
+                            .next()
+                            .map_or((self.rows.len() - 1, col, '?'), |(row_idx, char)| {
+                                (*row_idx, col, *char)
+                            })
+                    }
+                };
+
+                match photon.direction {
+                    Direction::North | Direction::South => visited_tiles
+                        .extend((row.min(new_row)..=row.max(new_row)).map(|i| (i, col))),
+                    Direction::East | Direction::West => visited_tiles
+                        .extend((col.min(new_col)..=col.max(new_col)).map(|i| (row, i))),
+                }
+
+                let position = (new_row, new_col);
+
+                let new = self.tile_beam_result(&tile, position, photon.direction);
+
+                photon_history.insert(photon);
+
+                return new;
+            });
+
+            photons = new_photons.collect();
+        }
+
+        return visited_tiles.len() as u64;
+    }
+
+    fn tile_beam_result(
+        &self,
+        tile: &char,
+        position: Position,
+        direction: Direction,
+    ) -> Vec<Photon> {
+        let directions = match (tile, direction) {
+            ('/', Direction::North | Direction::South)
+            | ('\\', Direction::East | Direction::West) => {
+                vec![direction.turn_right()]
+            }
+            ('/', _) | ('\\', _) => vec![direction.turn_left()],
+            ('|', _) => vec![Direction::North, Direction::South],
+            ('-', _) => vec![Direction::West, Direction::East],
+            _ => vec![],
+        };
+
+        return directions
+            .into_iter()
+            .map(|direction| Photon {
+                position,
+                direction,
+            })
+            .collect();
+    }
+
+    /// The most tiles energized by a beam entering from any edge.
+    pub fn find_max_configuration(&self) -> u64 {
+        let top_edge = (0..self.cols.len()).map(|i| Photon {
+            position: (0, i),
+            direction: Direction::South,
+        });
+        let bottom_edge = (0..self.cols.len()).map(|i| Photon {
+            position: (self.rows.len() - 1, i),
+            direction: Direction::North,
+        });
+        let left_edge = (0..self.rows.len()).map(|i| Photon {
+            position: (i, 0),
+            direction: Direction::East,
+        });
+        let right_edge = (0..self.rows.len()).map(|i| Photon {
+            position: (i, self.cols.len() - 1),
+            direction: Direction::West,
+        });
+
+        let chain = top_edge
+            .chain(bottom_edge)
+            .chain(left_edge)
+            .chain(right_edge);
+
+        return chain
+            .map(|photon| self.count_activated_tiles(photon))
+            .max()
+            .unwrap();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use crate::{generate, Contraption, Photon};
+
+    const INPUT: &str = aoc::example!("example");
+
+    #[test]
+    fn test_count_activated_tiles() {
+        let result = Contraption::from_str(INPUT)
+            .expect("Failed to Parse")
+            .count_activated_tiles(Photon {
+                position: (0, 0),
+                direction: aoc::Direction::East,
+            });
+
+        assert_eq!(result, 46);
+    }
+
+    #[test]
+    fn test_max_activated_tiles() {
+        let result = Contraption::from_str(INPUT)
+            .expect("Failed to Parse")
+            .find_max_configuration();
+
+        assert_eq!(result, 51);
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 30);
+            let contraption = Contraption::from_str(&input).expect("Failed to Parse");
+
+            let tiles = contraption.count_activated_tiles(Photon {
+                position: (0, 0),
+                direction: aoc::Direction::East,
+            });
+
+            assert!((1..=900).contains(&tiles));
+            assert!(contraption.find_max_configuration() >= tiles);
+        }
+    }
+}
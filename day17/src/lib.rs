@@ -0,0 +1,153 @@
+use aoc::{Direction, Grid, Point, Position};
+
+/// Day 17: Clumsy Crucible.
+pub struct Day17;
+
+impl aoc::Solution for Day17 {
+    type Input = CityMap;
+
+    fn parse(input: &str) -> CityMap {
+        CityMap::from(input)
+    }
+
+    fn part1(map: &CityMap) -> Option<aoc::Answer> {
+        map.find_best_path(0, 3).map(aoc::Answer::from)
+    }
+
+    fn part2(map: &CityMap) -> Option<aoc::Answer> {
+        map.find_best_path(4, 10).map(aoc::Answer::from)
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 17);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register_solution::<Day17>(DAY);
+    registry.register_generator(DAY, generate);
+}
+
+/// A `size` by `size` map of heat losses from 1 to 9.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        input.extend((0..size).map(|_| char::from(b'0' + rng.range(1..10) as u8)));
+        input += "\n";
+    }
+
+    return input;
+}
+
+/// Where the crucible is, which way it is heading and how many blocks it has
+/// gone that way.
+type Crucible = (Position, Option<Direction>, u32);
+
+/// Heat loss of every city block.
+pub struct CityMap {
+    grid: Grid<u32>,
+}
+
+impl From<&str> for CityMap {
+    fn from(value: &str) -> Self {
+        let grid = Grid::parse(value, |c| c.to_digit(10).unwrap());
+
+        return Self { grid };
+    }
+}
+
+impl CityMap {
+    /// Least heat loss from the top left to the bottom right block, for a
+    /// crucible moving between `min_dist` and `max_dist` blocks per turn.
+    pub fn find_best_path(&self, min_dist: u32, max_dist: u32) -> Option<u64> {
+        let end_position = (self.grid.height() - 1, self.grid.width() - 1);
+
+        // Nothing is heading anywhere yet at the start, so it may turn any way.
+        let start: Crucible = ((0, 0), None, 0);
+
+        let successors = |&(position, heading, distance): &Crucible| {
+            aoc::trace!("at {:?} heading {:?} for {}", position, heading, distance);
+
+            let ahead = heading
+                .filter(|_| distance < max_dist)
+                .map(|direction| (direction, distance + 1));
+
+            let can_turn = distance >= min_dist || heading.is_none();
+            let turns = Direction::ALL
+                .into_iter()
+                .filter(move |d| {
+                    can_turn
+                        && heading.is_none_or(|heading| *d != heading && *d != heading.reverse())
+                })
+                .map(|direction| (direction, 1));
+
+            ahead
+                .into_iter()
+                .chain(turns)
+                .filter_map(move |(direction, distance)| {
+                    let next = self.grid.step(position, direction)?;
+                    Some(((next, Some(direction), distance), self.grid[next] as usize))
+                })
+        };
+
+        // Every block costs at least 1, so the distance left never overestimates.
+        let heuristic = |&(position, _, _): &Crucible| {
+            Point::from(position).manhattan(Point::from(end_position))
+        };
+
+        let is_goal =
+            |&(position, _, distance): &Crucible| position == end_position && distance >= min_dist;
+
+        let path = aoc::search::astar_buckets(start, successors, heuristic, is_goal)?;
+
+        aoc::debug!(
+            "reached the end with heat loss {} after {} blocks",
+            path.cost,
+            path.states.len() - 1
+        );
+
+        return Some(path.cost as u64);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, CityMap};
+
+    const INPUT: &str = aoc::example!("example");
+
+    #[test]
+    fn test_find_best_path() {
+        let result = CityMap::from(INPUT).find_best_path(0, 3);
+
+        assert_eq!(result, Some(102));
+    }
+
+    #[test]
+    fn test_find_best_path_ultra() {
+        let result = CityMap::from(INPUT).find_best_path(4, 10);
+
+        assert_eq!(result, Some(94));
+    }
+
+    #[test]
+    fn test_find_best_path_ultra_2() {
+        let input = aoc::example!("example2");
+
+        let result = CityMap::from(input).find_best_path(4, 10);
+
+        assert_eq!(result, Some(71));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..5 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 15);
+            let map = CityMap::from(input.as_str());
+
+            // Any path costs at least one per block and at most nine.
+            let loss = map.find_best_path(1, 3).unwrap();
+            assert!((28..=9 * 28).contains(&loss));
+            assert!(map.find_best_path(4, 10).is_some());
+        }
+    }
+}
@@ -0,0 +1,224 @@
+use std::cmp::Ordering;
+
+use aoc::{Direction, Point};
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 18);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, |input| {
+        let part_1 = Dig::<false>::from(input).get_lava_capacity();
+        let part_2 = Dig::<true>::from(input).get_lava_capacity();
+
+        return (Some(part_1.into()), Some(part_2.into()));
+    });
+    registry.register_generator(DAY, generate);
+}
+
+/// The dig plan, read from the directions and lengths or, when `ADV`, from
+/// the colour codes.
+pub struct Dig<const ADV: bool> {
+    instructions: Vec<(Direction, u64)>,
+}
+
+impl<const ADV: bool> From<&str> for Dig<ADV> {
+    fn from(value: &str) -> Self {
+        let instructions = value
+            .lines()
+            .map(|l| {
+                let mut parts = l.split_whitespace();
+
+                let [dir, dist, col] = [parts.next(), parts.next(), parts.next()];
+
+                match ADV {
+                    false => {
+                        let dir = dir.unwrap().parse().expect("Invalid Direction");
+                        let dist = dist.unwrap().parse().unwrap();
+
+                        (dir, dist)
+                    }
+                    true => {
+                        let dist = col.unwrap().get(2..7).unwrap();
+                        let dist = u64::from_str_radix(dist, 16).unwrap();
+                        let dir = match col.unwrap().get(7..8) {
+                            Some("3") => Direction::North,
+                            Some("1") => Direction::South,
+                            Some("2") => Direction::West,
+                            Some("0") => Direction::East,
+                            _ => panic!("Invalid Direction"),
+                        };
+                        (dir, dist)
+                    }
+                }
+            })
+            .collect();
+
+        return Self { instructions };
+    }
+}
+
+impl<const ADV: bool> Dig<ADV> {
+    /// Cubic metres of lava the dug out lagoon holds.
+    pub fn get_lava_capacity(&self) -> u64 {
+        let (_, x_sum, y_sum, perim) = self.instructions.iter().fold(
+            (Point::ORIGIN, 0, 0, 0),
+            |(last, x_sum, y_sum, perim), (dir, dist)| {
+                let next = last + dir.vector() * *dist as isize;
+
+                // Shoelace theorem
+                let x_sum = x_sum + (last.row * next.col);
+                let y_sum = y_sum + (last.col * next.row);
+                let perim = perim + dist;
+
+                return (next, x_sum, y_sum, perim);
+            },
+        );
+
+        let area = ((x_sum.abs_diff(y_sum)) / 2) as u64;
+
+        let area = area + (perim / 2) + 1;
+
+        return area;
+    }
+}
+
+/// A dig plan tracing a random loop on a `size` by `size` grid of blocks,
+/// stretched to different lengths for each part. The colour codes trace the
+/// same loop mirrored along its diagonal, so both parts turn as often.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let size = size.max(1);
+    let mut path = aoc::gen::simple_loop(rng, size, size);
+
+    // Start on a corner, so that no straight line wraps around the end.
+    let turns = |path: &[(usize, usize)], i: usize| {
+        let (a, b) = (
+            path[(i + path.len() - 1) % path.len()],
+            path[(i + 1) % path.len()],
+        );
+        a.0 != b.0 && a.1 != b.1
+    };
+    let corner = (0..path.len()).find(|&i| turns(&path, i)).unwrap();
+    path.rotate_left(corner);
+
+    let mut lengths = |max: u64| -> [Vec<u64>; 2] {
+        [0, 1].map(|_| (0..size).map(|_| rng.range(1..max + 1)).collect())
+    };
+    let [rows, cols] = lengths(10);
+    let [rows_2, cols_2] = lengths(0xFFFFF / size as u64);
+
+    let mut input = String::new();
+    let mut start = 0;
+
+    for end in 1..=path.len() {
+        if end < path.len() && !turns(&path, end) {
+            continue;
+        }
+
+        let (from, to) = (path[start], path[end % path.len()]);
+        let span =
+            |lengths: &[u64], a: usize, b: usize| lengths[a.min(b)..a.max(b)].iter().sum::<u64>();
+
+        let (direction, length, code, length_2) = match (to.0.cmp(&from.0), to.1.cmp(&from.1)) {
+            (Ordering::Less, _) => (
+                'U',
+                span(&rows, from.0, to.0),
+                2,
+                span(&cols_2, from.0, to.0),
+            ),
+            (Ordering::Greater, _) => (
+                'D',
+                span(&rows, from.0, to.0),
+                0,
+                span(&cols_2, from.0, to.0),
+            ),
+            (_, Ordering::Less) => (
+                'L',
+                span(&cols, from.1, to.1),
+                3,
+                span(&rows_2, from.1, to.1),
+            ),
+            _ => (
+                'R',
+                span(&cols, from.1, to.1),
+                1,
+                span(&rows_2, from.1, to.1),
+            ),
+        };
+
+        input += &format!("{} {} (#{:05x}{})\n", direction, length, length_2, code);
+        start = end;
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use aoc::{Direction, Point};
+
+    use crate::{generate, Dig};
+
+    const INPUT: &str = aoc::example!("example");
+
+    #[test]
+    fn test_calculate_lava_capacity() {
+        let result = Dig::<false>::from(INPUT).get_lava_capacity();
+        assert_eq!(result, 62)
+    }
+
+    #[test]
+    fn test_calculate_lava_capacity_2() {
+        let result = Dig::<true>::from(INPUT).get_lava_capacity();
+        assert_eq!(result, 952408144115)
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 6);
+
+            // Dig the trench out on a grid and flood fill around it.
+            let mut position = Point::ORIGIN;
+            let mut trench = HashSet::from([position]);
+            for line in input.lines() {
+                let mut parts = line.split_whitespace();
+                let (direction, length) = (parts.next().unwrap(), parts.next().unwrap());
+                let step = direction.parse::<Direction>().unwrap().vector();
+
+                for _ in 0..length.parse::<u64>().unwrap() {
+                    position += step;
+                    trench.insert(position);
+                }
+            }
+            assert_eq!(position, Point::ORIGIN);
+
+            let rows = trench.iter().map(|p| p.row).min().unwrap() - 1
+                ..=trench.iter().map(|p| p.row).max().unwrap() + 1;
+            let cols = trench.iter().map(|p| p.col).min().unwrap() - 1
+                ..=trench.iter().map(|p| p.col).max().unwrap() + 1;
+
+            let corner = Point::new(*rows.start(), *cols.start());
+            let mut outside = HashSet::from([corner]);
+            let mut queue = vec![corner];
+            while let Some(point) = queue.pop() {
+                for next in Direction::ALL.map(|d| point + d.vector()) {
+                    if rows.contains(&next.row)
+                        && cols.contains(&next.col)
+                        && !trench.contains(&next)
+                        && outside.insert(next)
+                    {
+                        queue.push(next);
+                    }
+                }
+            }
+
+            let area = (rows.end() - rows.start() + 1) * (cols.end() - cols.start() + 1);
+            assert_eq!(
+                Dig::<false>::from(input.as_str()).get_lava_capacity(),
+                (area - outside.len() as isize) as u64
+            );
+            assert!(Dig::<true>::from(input.as_str()).get_lava_capacity() > 0);
+        }
+    }
+}
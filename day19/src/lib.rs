@@ -0,0 +1,328 @@
+use std::{
+    collections::{HashMap, HashSet},
+    ops::Range,
+};
+
+/// Day 19: Aplenty.
+pub struct Day19;
+
+impl aoc::Solution for Day19 {
+    type Input = PartSystem;
+
+    fn parse(input: &str) -> PartSystem {
+        PartSystem::from(input)
+    }
+
+    fn part1(system: &PartSystem) -> Option<aoc::Answer> {
+        Some(system.sum_accepted_parts().into())
+    }
+
+    fn part2(system: &PartSystem) -> Option<aoc::Answer> {
+        Some(system.find_distinct_combinations().into())
+    }
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 19);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register_solution::<Day19>(DAY);
+    registry.register_generator(DAY, generate);
+}
+
+/// About `size` workflows branching out from `in`, without any cycles, and
+/// `size` parts to sort with them.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let name = |rng: &mut aoc::gen::Rng| -> String {
+        (0..rng.range(2..4))
+            .map(|_| (b'a' + rng.below(26) as u8) as char)
+            .collect()
+    };
+
+    let mut names = HashSet::from(["in".to_string()]);
+    let mut pending = vec!["in".to_string()];
+    let mut workflows = Vec::new();
+
+    // Every workflow only sends parts on to workflows created after it.
+    while let Some(workflow) = pending.pop() {
+        let mut target = |rng: &mut aoc::gen::Rng| -> String {
+            if names.len() < size && rng.chance(0.6) {
+                loop {
+                    let next = name(rng);
+                    if names.insert(next.clone()) {
+                        pending.push(next.clone());
+                        return next;
+                    }
+                }
+            }
+
+            return rng.choose(&["A", "R"]).to_string();
+        };
+
+        let mut rules: Vec<String> = (0..rng.range(1..4))
+            .map(|_| {
+                let attr = rng.choose(&['x', 'm', 'a', 's']);
+                let op = rng.choose(&['<', '>']);
+                let value = rng.range(1..4001);
+                format!("{}{}{}:{}", attr, op, value, target(rng))
+            })
+            .collect();
+        rules.push(target(rng));
+
+        workflows.push(format!("{}{{{}}}", workflow, rules.join(",")));
+    }
+
+    rng.shuffle(&mut workflows);
+
+    let parts: Vec<String> = (0..size)
+        .map(|_| {
+            let [x, m, a, s] = [0; 4].map(|_| rng.range(1..4001));
+            format!("{{x={},m={},a={},s={}}}", x, m, a, s)
+        })
+        .collect();
+
+    return format!("{}\n\n{}\n", workflows.join("\n"), parts.join("\n"));
+}
+
+#[derive(Debug)]
+struct Part {
+    x: u64,
+    m: u64,
+    a: u64,
+    s: u64,
+}
+
+impl From<&str> for Part {
+    fn from(value: &str) -> Self {
+        // {x=787,m=2655,a=1222,s=2876}
+        let p_map: HashMap<&str, &str> = HashMap::from_iter(
+            value
+                .get(1..value.len() - 1)
+                .unwrap()
+                .split(",")
+                .map(|p| p.split_once("=").unwrap()),
+        );
+
+        return Self {
+            x: p_map.get("x").unwrap().parse().unwrap(),
+            m: p_map.get("m").unwrap().parse().unwrap(),
+            a: p_map.get("a").unwrap().parse().unwrap(),
+            s: p_map.get("s").unwrap().parse().unwrap(),
+        };
+    }
+}
+
+/// Inclusive-exclusive rating ranges, indexed in `xmas` order.
+type RatingRanges = [Range<u64>; 4];
+
+fn attr_index(attr: char) -> usize {
+    match attr {
+        'x' => 0,
+        'm' => 1,
+        'a' => 2,
+        's' => 3,
+        _ => panic!("Invalid Attr"),
+    }
+}
+
+/// The workflows, and the parts to sort through them.
+#[derive(Debug)]
+pub struct PartSystem {
+    parts: Vec<Part>,
+    workflows: HashMap<String, Vec<String>>,
+}
+
+impl From<&str> for PartSystem {
+    fn from(value: &str) -> Self {
+        let (workflows, parts) = value.split_once("\n\n").unwrap();
+
+        let parts = parts.lines().map(Part::from).collect();
+
+        let workflows = HashMap::from_iter(workflows.lines().map(|l| {
+            // px{a<2006:qkq,m>2090:A,rfg}
+
+            let (key, w) = l.split_once("{").unwrap();
+
+            let rules = w
+                .replace("}", "")
+                .split(',')
+                .map(String::from)
+                .collect();
+
+            (key.to_owned(), rules)
+        }));
+
+        return Self { parts, workflows };
+    }
+}
+
+impl PartSystem {
+    fn evalutate_workflow(&self, workflow: &str, part: &Part) -> bool {
+        let workflow = self.workflows.get(workflow).expect("Invalid workflow");
+
+        let result = workflow.iter().find_map(|rule| {
+            // a<2006:qkq
+
+            let (rule, outcome) = match rule.split_once(":") {
+                Some((a, b)) => (Some(a), b),
+                None => (None, rule.as_str()),
+            };
+
+            let rule = rule.map(|r| {
+                let mut r = r.chars();
+                let attr = r.next().unwrap();
+                let comp = r.next().unwrap();
+                let val: u64 = r.collect::<String>().parse().unwrap();
+
+                let p_val = match attr {
+                    'x' => part.x,
+                    'm' => part.m,
+                    'a' => part.a,
+                    's' => part.s,
+                    _ => panic!("Invalid Attr"),
+                };
+
+                match comp {
+                    '>' => p_val.gt(&val),
+                    '<' => p_val.lt(&val),
+                    _ => panic!("Invalid comparison"),
+                }
+            });
+
+            match rule {
+                Some(true) => Some(outcome),
+                Some(false) => None,
+                None => Some(outcome),
+            }
+        });
+
+        match result {
+            Some("A") => true,
+            Some("R") => false,
+            Some(next) => self.evalutate_workflow(next, part),
+            None => panic!("Workflow didn't get a result!"),
+        }
+    }
+
+    fn is_part_accepted(&self, part: &Part) -> bool {
+        self.evalutate_workflow("in", part)
+    }
+
+    /// Sums the ratings of every accepted part.
+    pub fn sum_accepted_parts(&self) -> u64 {
+        let sum = self
+            .parts
+            .iter()
+            .filter(|p| self.is_part_accepted(p))
+            .map(|p| p.x + p.m + p.a + p.s)
+            .sum();
+
+        return sum;
+    }
+
+    fn rule_find_in_out_range<'a>(
+        &self,
+        rule: &'a str,
+        eval_ranges: RatingRanges,
+    ) -> (Option<RatingRanges>, Option<RatingRanges>, &'a str) {
+        // a<2006:qkq
+
+        let (rule, outcome) = match rule.split_once(":") {
+            Some((a, b)) => (a, b),
+            None => return (Some(eval_ranges), None, rule),
+        };
+
+        let mut r = rule.chars();
+        let attr = attr_index(r.next().unwrap());
+        let comp = r.next().unwrap();
+        let val: u64 = r.collect::<String>().parse().unwrap();
+
+        let eval_range = &eval_ranges[attr];
+        let clamp = |v: u64| v.clamp(eval_range.start, eval_range.end);
+
+        let (in_range, out_range) = match comp {
+            '<' => (
+                eval_range.start..clamp(val),
+                clamp(val)..eval_range.end,
+            ),
+            '>' => (
+                clamp(val + 1)..eval_range.end,
+                eval_range.start..clamp(val + 1),
+            ),
+            _ => panic!("invalid comparison"),
+        };
+
+        let split = |range: Range<u64>| {
+            if range.is_empty() {
+                return None;
+            }
+            let mut ranges = eval_ranges.clone();
+            ranges[attr] = range;
+            return Some(ranges);
+        };
+
+        return (split(in_range), split(out_range), outcome);
+    }
+
+    fn count_accepted(&self, workflow: &str, ranges: RatingRanges) -> u64 {
+        match workflow {
+            "A" => return ranges.iter().map(|r| r.end - r.start).product(),
+            "R" => return 0,
+            _ => {}
+        }
+
+        let workflow = self.workflows.get(workflow).expect("Invalid workflow");
+
+        let (count, _) = workflow
+            .iter()
+            .fold((0, Some(ranges)), |(count, remaining), rule| match remaining {
+                Some(remaining) => {
+                    let (in_ranges, out_ranges, outcome) =
+                        self.rule_find_in_out_range(rule, remaining);
+
+                    let accepted = in_ranges.map_or(0, |r| self.count_accepted(outcome, r));
+
+                    (count + accepted, out_ranges)
+                }
+                None => (count, None),
+            });
+
+        return count;
+    }
+
+    /// Counts the combinations of ratings from 1 to 4000 that are accepted.
+    pub fn find_distinct_combinations(&self) -> u64 {
+        self.count_accepted("in", [1..4001, 1..4001, 1..4001, 1..4001])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, PartSystem};
+
+    const INPUT: &str = aoc::example!("example");
+
+    #[test]
+    fn test_evaluate_parts() {
+        let result = PartSystem::from(INPUT).sum_accepted_parts();
+
+        assert_eq!(result, 19114)
+    }
+
+    #[test]
+    fn test_find_distinct_combinations() {
+        let result = PartSystem::from(INPUT).find_distinct_combinations();
+
+        assert_eq!(result, 167409079868000)
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 50);
+            let system = PartSystem::from(input.as_str());
+
+            assert!(system.find_distinct_combinations() <= 4000_u64.pow(4));
+            assert!(system.sum_accepted_parts() <= 50 * 4 * 4000);
+        }
+    }
+}
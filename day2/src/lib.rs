@@ -0,0 +1,153 @@
+#[derive(Debug, PartialEq)]
+struct Game {
+    id: u32,
+    max_blue: u32,
+    max_red: u32,
+    max_green: u32,
+    power: u32,
+}
+
+fn parse_game(input: &str) -> Game {
+    let (game_str, rounds_str) = input.split_once(": ").expect("Failed to parse game");
+
+    let (_, game_id) = game_str.split_once(" ").expect("Mising Game ID");
+
+    let game_id = game_id.parse::<u32>().expect("Game ID not an int");
+
+    let mut max_blue = 0;
+    let mut max_red = 0;
+    let mut max_green = 0;
+
+    for round_str in rounds_str.split("; ") {
+        for colour_str in round_str.split(", ") {
+            let (amount, colour) = colour_str
+                .split_once(" ")
+                .expect("Failed to parse colour_str");
+            let amount = amount.parse::<u32>().unwrap();
+
+            match colour {
+                "red" => {
+                    if amount > max_red {
+                        max_red = amount;
+                    };
+                }
+                "green" => {
+                    if amount > max_green {
+                        max_green = amount;
+                    };
+                }
+                "blue" => {
+                    if amount > max_blue {
+                        max_blue = amount;
+                    };
+                }
+                &_ => {}
+            };
+        }
+    }
+
+    return Game {
+        id: game_id,
+        max_blue,
+        max_red,
+        max_green,
+        power: max_blue * max_green * max_red,
+    };
+}
+
+/// Sums the ids of the games possible with 12 red, 13 green and 14 blue cubes,
+/// and the powers of the smallest set of cubes for each game.
+pub fn sum_games(input: &str) -> aoc::Result {
+    let mut sum_matching: u32 = 0;
+    let mut sum_powers: u32 = 0;
+
+    for game_str in input.lines() {
+        let game = parse_game(game_str);
+
+        sum_powers += game.power;
+
+        if game.max_red <= 12 && game.max_green <= 13 && game.max_blue <= 14 {
+            sum_matching += game.id;
+        }
+    }
+
+    return (Some(sum_matching.into()), Some(sum_powers.into()));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 2);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, sum_games);
+    registry.register_generator(DAY, generate);
+}
+
+/// `size` games of up to six rounds, each drawing up to 20 cubes of a colour.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for id in 1..=size {
+        let rounds: Vec<String> = (0..rng.range(1..7))
+            .map(|_| {
+                let mut colours = ["red", "green", "blue"];
+                rng.shuffle(&mut colours);
+
+                colours[..rng.range(1..4) as usize]
+                    .iter()
+                    .map(|colour| format!("{} {}", rng.range(1..21), colour))
+                    .collect::<Vec<String>>()
+                    .join(", ")
+            })
+            .collect();
+
+        input += &format!("Game {}: {}\n", id, rounds.join("; "));
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{generate, parse_game, sum_games, Game};
+
+    #[test]
+    fn parse_game_test() {
+        let examples = [(
+            "Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green",
+            Game {
+                id: 1,
+                max_blue: 6,
+                max_green: 2,
+                max_red: 4,
+                power: 48,
+            },
+        )];
+
+        for (input, expected) in examples {
+            let result = parse_game(input);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_games_sum() {
+        let games = aoc::example!("example");
+
+        let result = sum_games(games);
+
+        assert_eq!(result, (Some(8.into()), Some(2286.into())));
+    }
+
+    #[test]
+    fn test_generate() {
+        let input = generate(&mut aoc::gen::Rng::new(2), 100);
+        let games: Vec<Game> = input.lines().map(parse_game).collect();
+
+        assert_eq!(games.len(), 100);
+        assert!(games
+            .iter()
+            .all(|g| g.max_red.max(g.max_green).max(g.max_blue) <= 20));
+        assert_eq!(input, generate(&mut aoc::gen::Rng::new(2), 100));
+    }
+}
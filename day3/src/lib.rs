@@ -0,0 +1,299 @@
+#[derive(Clone, Debug, PartialEq)]
+struct Part {
+    value: u32,
+    row: usize,
+    cols: (usize, usize),
+}
+
+#[derive(Clone, Debug, PartialEq)]
+
+struct Gear {
+    row: usize,
+    col: usize,
+}
+
+fn parse_line(input: &str, row: usize) -> (Vec<Part>, Vec<Gear>) {
+    let mut parts: Vec<Part> = [].to_vec();
+    let mut gears: Vec<Gear> = [].to_vec();
+
+    let mut number_start: Option<usize> = None;
+
+    for (index, char) in input.char_indices() {
+        let is_digit = char.is_ascii_digit();
+
+        if char == '*' {
+            gears.push(Gear { row, col: index })
+        }
+
+        match (is_digit, number_start) {
+            (true, None) => number_start = Some(index),
+            (false, Some(start)) => {
+                let number = input.get(start..index).unwrap();
+                let number = number.parse::<u32>().unwrap();
+                parts.push(Part {
+                    value: number,
+                    row,
+                    cols: (start, index),
+                });
+                number_start = None
+            }
+            _ => {}
+        };
+    }
+
+    if let Some(start) = number_start {
+        let number = input.get(start..).unwrap();
+        let number = number.parse::<u32>().unwrap();
+        parts.push(Part {
+            value: number,
+            row,
+            cols: (start, input.len()),
+        });
+    }
+
+    return (parts, gears);
+}
+
+/// Sums the part numbers adjacent to a symbol, and the ratios of every gear.
+pub fn parse_schematic(input: &str) -> aoc::Result {
+    let mut possible_parts: Vec<Part> = [].to_vec();
+    let mut possible_gears: Vec<Gear> = [].to_vec();
+
+    let lines: Vec<&str> = input.lines().collect();
+    let lines_length = lines.len();
+
+    for row in 0..lines.len() {
+        let (mut parts, mut gears) = parse_line(lines.get(row).unwrap(), row);
+
+        possible_parts.append(&mut parts);
+        possible_gears.append(&mut gears);
+    }
+
+    let mut parts_sum = 0;
+    let mut gear_ratios_sum = 0;
+    let mut true_parts: Vec<Part> = [].to_vec();
+
+    for part in possible_parts {
+        let mut matched = false;
+
+        let row_above = part.row.checked_add_signed(-1).unwrap_or(0);
+        let row_below = part
+            .row
+            .checked_add(2)
+            .unwrap_or(lines_length)
+            .min(lines_length);
+
+        let neighbor_rows = row_above..row_below;
+
+        for row in neighbor_rows {
+            if matched {
+                break;
+            }
+            let (start_col, end_col) = part.cols;
+            let col_before = start_col.checked_add_signed(-1).unwrap_or(0);
+            let col_after = end_col
+                .checked_add(1)
+                .unwrap_or(lines_length)
+                .min(lines_length);
+
+            let neighbor_cols = col_before..col_after;
+
+            for col in neighbor_cols {
+                let chars: Vec<char> = lines.get(row).unwrap().chars().collect();
+                let char = chars.get(col).unwrap();
+                let char = char.to_owned();
+                if char != '.' && !char.is_ascii_digit() {
+                    matched = true;
+                    break;
+                }
+            }
+        }
+
+        if matched {
+            parts_sum += part.value;
+            true_parts.push(part);
+        }
+    }
+
+    for gear in possible_gears {
+        let row_above = gear.row.checked_add_signed(-1).unwrap_or(0);
+        let row_below = gear
+            .row
+            .checked_add(2)
+            .unwrap_or(lines_length)
+            .min(lines_length);
+
+        let neighbor_rows = row_above..row_below;
+
+        let mut neighbors: Vec<Part> = [].to_vec();
+
+        for row in neighbor_rows {
+            let col_before = gear.col.checked_add_signed(-1).unwrap_or(0);
+            let col_after = gear
+                .col
+                .checked_add(2)
+                .unwrap_or(lines_length)
+                .min(lines_length);
+
+            let neighbor_cols = col_before..col_after;
+
+            for col in neighbor_cols {
+                let part = true_parts
+                    .iter()
+                    .find(|p| (p.cols.0..p.cols.1).contains(&col) && p.row == row);
+
+                if let Some(p) = part {
+                    if !neighbors.contains(p) {
+                        neighbors.push(p.clone())
+                    }
+                }
+            }
+        }
+
+        if neighbors.len() == 2 {
+            let gear_ratio = neighbors.iter().fold(1, |acc, x| acc * x.value);
+            gear_ratios_sum += gear_ratio;
+        }
+    }
+
+    return (Some(parts_sum.into()), Some(gear_ratios_sum.into()));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 3);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, parse_schematic);
+    registry.register_generator(DAY, generate);
+}
+
+/// A `size` by `size` schematic with numbers up to 999 and scattered symbols.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    const SYMBOLS: [char; 10] = ['*', '#', '+', '$', '/', '@', '=', '%', '&', '-'];
+
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let mut row = vec!['.'; size];
+        let mut col = 0;
+
+        // Every number is followed by at least one `.`, so that neighbouring
+        // numbers never run together.
+        while col < size {
+            if rng.chance(0.15) {
+                let number = rng.range(1..1000).to_string();
+                if col + number.len() <= size {
+                    row.splice(col..col + number.len(), number.chars());
+                    col += number.len();
+                }
+            } else if rng.chance(0.1) {
+                row[col] = *rng.choose(&SYMBOLS);
+            }
+
+            col += 1;
+        }
+
+        input.extend(row);
+        input += "\n";
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+
+    use crate::{generate, parse_line, parse_schematic, Gear, Part};
+
+    #[test]
+    fn parse_line_test() {
+        let examples = [
+            (
+                "123",
+                (
+                    [Part {
+                        value: 123,
+                        row: 0,
+                        cols: (0, 3),
+                    }]
+                    .to_vec(),
+                    [].to_vec(),
+                ),
+            ),
+            (
+                ".123..456...",
+                (
+                    [
+                        Part {
+                            value: 123,
+                            row: 0,
+                            cols: (1, 4),
+                        },
+                        Part {
+                            value: 456,
+                            row: 0,
+                            cols: (6, 9),
+                        },
+                    ]
+                    .to_vec(),
+                    [].to_vec(),
+                ),
+            ),
+            (
+                "1.123.$456..**.4",
+                (
+                    [
+                        Part {
+                            value: 1,
+                            row: 0,
+                            cols: (0, 1),
+                        },
+                        Part {
+                            value: 123,
+                            row: 0,
+                            cols: (2, 5),
+                        },
+                        Part {
+                            value: 456,
+                            row: 0,
+                            cols: (7, 10),
+                        },
+                        Part {
+                            value: 4,
+                            row: 0,
+                            cols: (15, 16),
+                        },
+                    ]
+                    .to_vec(),
+                    [Gear { row: 0, col: 12 }, Gear { row: 0, col: 13 }].to_vec(),
+                ),
+            ),
+        ];
+
+        for (input, expected) in examples {
+            let result = parse_line(input, 0);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_schematic() {
+        let input = aoc::example!("example");
+
+        let (part_1, part_2) = parse_schematic(input);
+
+        assert_eq!(part_1, Some(4361.into()));
+        assert_eq!(part_2, Some(467835.into()));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 30);
+            assert!(input.lines().all(|l| l.len() == 30));
+
+            let (part_1, part_2) = parse_schematic(&input);
+            assert!(part_1.is_some() && part_2.is_some());
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+struct CardScore {
+    id: u32,
+    matches: u32,
+    power_score: u32,
+}
+fn score_card(card_str: &str) -> CardScore {
+    let (card_description, card_contents) = card_str.split_once(": ").expect("Couldn't parse card");
+
+    let (_, id_str) = card_description
+        .split_once(" ")
+        .expect("Couldn't parse description");
+    let id_str = id_str.trim();
+    let id = id_str.parse::<u32>().expect("Invalid Card Id");
+
+    let (winning_numbers, actual_numbers) = card_contents
+        .split_once(" | ")
+        .expect("Couldn't split card sections");
+
+    let winning_numbers: Vec<u32> = winning_numbers
+        .split_whitespace()
+        .map(|i| i.parse::<u32>().expect("Couldn't parse item"))
+        .collect();
+
+    let matches: u32 = actual_numbers
+        .split_whitespace()
+        .map(|i| i.parse::<u32>().expect("Couldn't parse item"))
+        .filter(|i| winning_numbers.contains(i))
+        .count()
+        .try_into()
+        .expect("Invalid Count Size");
+
+    let power_score = match matches {
+        0 => 0,
+        _ => 2u32.checked_pow(matches - 1).expect("Score overflowed"),
+    };
+
+    return CardScore {
+        id,
+        matches,
+        power_score,
+    };
+}
+
+/// Scores the scratchcards, and counts the copies won by playing them all.
+pub fn score_set(input: &str) -> aoc::Result {
+    let part_1 = input
+        .lines()
+        .map(|card| score_card(card).power_score)
+        .sum::<u32>();
+
+    let part_2 = input
+        .lines()
+        .map(score_card)
+        .fold(HashMap::new(), |mut acc: HashMap<u32, u32>, card| {
+            let copies: u32 = *acc.entry(card.id).and_modify(|x| *x += 1).or_insert(1);
+
+            if card.matches > 0 {
+                for id in card.id + 1..card.id + card.matches + 1 {
+                    acc.entry(id).and_modify(|x| *x += copies).or_insert(copies);
+                }
+            };
+
+            return acc;
+        })
+        .values()
+        .sum::<u32>();
+
+    return (Some(part_1.into()), Some(part_2.into()));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 4);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, score_set);
+    registry.register_generator(DAY, generate);
+}
+
+/// `size` cards of 10 winning numbers and 25 numbers you have. Matches are
+/// kept low enough that the number of cards won never overflows.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let limit = u32::MAX as u64 / (2 * size as u64);
+    let mut copies = vec![1_u64; size];
+    let mut input = String::new();
+
+    for card in 0..size {
+        let matches = match rng.chance(0.5) {
+            true => 0,
+            false => (rng.range(1..11) as usize).min(size - card - 1),
+        };
+        let matches = match copies[card + 1..=card + matches]
+            .iter()
+            .any(|c| c + copies[card] > limit)
+        {
+            true => 0,
+            false => matches,
+        };
+
+        for won in card + 1..=card + matches {
+            copies[won] += copies[card];
+        }
+
+        let mut numbers: Vec<u64> = (1..100).collect();
+        rng.shuffle(&mut numbers);
+
+        let winning = &numbers[..10];
+        let mut have = [&numbers[..matches], &numbers[10..35 - matches]].concat();
+        rng.shuffle(&mut have);
+
+        let format = |numbers: &[u64]| {
+            numbers
+                .iter()
+                .map(|n| format!("{:>2}", n))
+                .collect::<Vec<String>>()
+                .join(" ")
+        };
+
+        input += &format!(
+            "Card {:>3}: {} | {}\n",
+            card + 1,
+            format(winning),
+            format(&have)
+        );
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate, score_card, score_set};
+
+    #[test]
+    fn test_score_card() {
+        let card_str = "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53";
+        let result = score_card(card_str);
+
+        assert_eq!(result.id, 1);
+        assert_eq!(result.matches, 4);
+        assert_eq!(result.power_score, 8);
+    }
+
+    #[test]
+    fn test_score_set() {
+        let input = aoc::example!("example");
+
+        let (part_1, part_2) = score_set(input);
+
+        assert_eq!(part_1, Some(13.into()));
+        assert_eq!(part_2, Some(30.into()));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 200);
+            assert!(input.lines().map(score_card).all(|card| card.matches <= 10));
+
+            let (part_1, part_2) = score_set(&input);
+            assert!(part_1.is_some() && part_2.is_some());
+        }
+    }
+}
@@ -0,0 +1,261 @@
+use std::{collections::BTreeMap, ops::Range};
+
+type Map = (u64, u64, u64);
+
+type MapTree = BTreeMap<u64, (u64, u64)>;
+
+fn find_map_dest(input: u64, maps: &[Map]) -> u64 {
+    let found = maps
+        .iter()
+        .find(|(_, start, len)| (start.to_owned()..start + len).contains(&input));
+
+    match found {
+        Some((dest_start, source_start, _)) => {
+            let offset = input - source_start;
+            return dest_start + offset;
+        }
+        None => input,
+    }
+}
+
+struct ReduceRanges {
+    found_ranges: Vec<Range<u64>>,
+    remaining_range: Option<Range<u64>>,
+}
+/// Maps `input` through one almanac map, splitting it wherever the map does.
+pub fn find_mapped_ranges(input: Range<u64>, map: &MapTree) -> Vec<Range<u64>> {
+    let result = map.range(..input.end).fold(
+        ReduceRanges {
+            found_ranges: [].to_vec(),
+            remaining_range: Some(input),
+        },
+        |mut acc: ReduceRanges, val| {
+            return match acc.remaining_range {
+                Some(remaining_range) => {
+                    let (source, (dest, length)) = val;
+
+                    let compare_range = source.to_owned()..source + length;
+
+                    let contains_start = compare_range.contains(&remaining_range.start);
+                    let contains_end = compare_range.contains(&remaining_range.end);
+
+                    let (found, remaining) = match (contains_start, contains_end) {
+                        (true, true) => (Some(remaining_range), None),
+                        (true, false) => (
+                            Some(remaining_range.start..compare_range.end),
+                            Some(compare_range.end..remaining_range.end),
+                        ),
+                        (false, true) => (
+                            Some(compare_range.start..remaining_range.end),
+                            Some(remaining_range.start..compare_range.start),
+                        ),
+                        (false, false) => (None, Some(remaining_range)),
+                    };
+
+                    if let Some(range) = found {
+                        let start_offset = range.start - compare_range.start;
+                        let end_offset = range.end - compare_range.start;
+                        let dest_start = dest + start_offset;
+                        let dest_end = dest + end_offset;
+                        acc.found_ranges.push(dest_start..dest_end);
+                    };
+
+                    acc.remaining_range = remaining;
+
+                    return acc;
+                }
+                None => acc,
+            };
+        },
+    );
+
+    let mut ranges = result.found_ranges;
+
+    if let Some(range) = result.remaining_range {
+        ranges.push(range);
+    }
+    return ranges;
+}
+
+/// Finds the lowest location for the listed seeds, then for the seed ranges.
+pub fn find_best_location(input: &str) -> aoc::Result {
+    let (seeds_line, maps) = input.split_once("\n\n").unwrap();
+
+    let seeds = seeds_line
+        .split_once(": ")
+        .map(|l| l.1)
+        .expect("Failed to parse seed line")
+        .split_whitespace()
+        .map(|i| i.parse::<u64>().expect("Invalid seed number"));
+
+    let maps = maps.split("\n\n").map(|maps| {
+        maps.lines()
+            .skip(1)
+            .map(|l| {
+                let mut items = l
+                    .splitn(3, " ")
+                    .map(|i| i.parse::<u64>().unwrap());
+
+                return (
+                    items.next().unwrap(),
+                    items.next().unwrap(),
+                    items.next().unwrap(),
+                );
+            })
+            .collect::<Vec<Map>>()
+    });
+
+    let part_1 = seeds
+        .clone()
+        .map(|seed| maps.clone().fold(seed, |acc, map| find_map_dest(acc, &map)))
+        .min()
+        .expect("No seeds in input!");
+
+    let map_trees: Vec<MapTree> = maps
+        .map(|m| {
+            m.iter().fold(BTreeMap::new(), |mut acc, val| {
+                acc.insert(val.1, (val.0, val.2));
+                return acc;
+            })
+        })
+        .collect();
+
+    let part_2 = seeds
+        .clone()
+        .step_by(2)
+        .zip(seeds.clone().skip(1).step_by(2))
+        .map(|(start, len)| start..start + len)
+        .map(|seed| {
+            map_trees
+                .iter()
+                .fold([seed].to_vec(), |acc: Vec<Range<u64>>, map_tree| {
+                    acc.iter()
+                        .flat_map(|range| find_mapped_ranges(range.to_owned(), map_tree))
+                        .collect()
+                })
+                .iter()
+                .map(|r| r.start)
+                .min()
+                .expect("No result found!")
+        })
+        .min()
+        .expect("No seeds in input!");
+
+    return (Some(part_1.into()), Some(part_2.into()));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 5);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, find_best_location);
+    registry.register_generator(DAY, generate);
+}
+
+/// Five seed ranges and seven maps, each shuffling `size` ranges of the
+/// numbers below 2^32 among themselves.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    const SPACE: u64 = 1 << 32;
+    const NAMES: [&str; 7] = [
+        "seed-to-soil",
+        "soil-to-fertilizer",
+        "fertilizer-to-water",
+        "water-to-light",
+        "light-to-temperature",
+        "temperature-to-humidity",
+        "humidity-to-location",
+    ];
+
+    let seeds: Vec<String> = (0..5)
+        .map(|_| {
+            let start = rng.range(0..SPACE - 1);
+            let len = rng.range(1..(SPACE - start).min(1 << 28));
+            format!("{} {}", start, len)
+        })
+        .collect();
+
+    let mut input = format!("seeds: {}\n", seeds.join(" "));
+
+    for name in NAMES {
+        let mut start = rng.range(0..SPACE / 2);
+        let sources: Vec<(u64, u64)> = (0..size)
+            .map(|_| {
+                let len = rng.range(1..SPACE / (2 * size as u64));
+                start += len;
+                (start - len, len)
+            })
+            .collect();
+        let mut order: Vec<usize> = (0..sources.len()).collect();
+        rng.shuffle(&mut order);
+
+        // Laying the ranges out again in a shuffled order gives each its
+        // destination, so no two sources map onto the same numbers.
+        let mut dest = sources[0].0;
+        let mut lines: Vec<String> = order
+            .iter()
+            .map(|&i| {
+                let (source, len) = sources[i];
+                let line = format!("{} {} {}", dest, source, len);
+                dest += len;
+                line
+            })
+            .collect();
+        rng.shuffle(&mut lines);
+
+        input += &format!("\n{} map:\n{}\n", name, lines.join("\n"));
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::{find_best_location, find_map_dest, find_mapped_ranges, generate, MapTree};
+
+    #[test]
+    fn test_find_map_dest() {
+        let maps = [(50, 98, 2), (52, 50, 48)].to_vec();
+
+        let examples = [(99, 51), (53, 55), (15, 15)];
+
+        for (input, expected) in examples {
+            let result = find_map_dest(input, &maps);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_find_mapped_ranges() {
+        let map: MapTree = BTreeMap::from([(50, (52, 20)), (98, (50, 2))]);
+
+        let examples = [(50..99, [(52..72), (50..51), (70..98)].to_vec())];
+
+        for (input, expected) in examples {
+            let result = find_mapped_ranges(input, &map);
+
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_find_best_location() {
+        let input = aoc::example!("example");
+
+        let (part_1, part_2) = find_best_location(input);
+
+        assert_eq!(part_1, Some(35.into()));
+        assert_eq!(part_2, Some(46.into()));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 30);
+            let (part_1, part_2) = find_best_location(&input);
+
+            assert!(part_1.is_some() && part_2.is_some());
+        }
+    }
+}
@@ -0,0 +1,135 @@
+/// Counts the hold times that travel further than `min_distance`.
+pub fn count_winning_options(duration: &u64, min_distance: &u64) -> u64 {
+    let rhs = *duration as f64 / 2.0;
+    let lhs: f64 = ((duration.pow(2) - 4 * min_distance) as f64).sqrt() / 2.0;
+
+    let min_root = (rhs - lhs).floor() as u64 + 1;
+    let max_root = (rhs + lhs).ceil() as u64;
+
+    return max_root - min_root;
+}
+
+/// Multiplies the winning options of every race, then solves the single
+/// race read by ignoring the spaces.
+pub fn parse_input(input: &str) -> aoc::Result {
+    let (time_str, dist_str) = input.split_once("\n").unwrap();
+
+    let times = time_str
+        .split_whitespace()
+        .skip(1)
+        .map(|str| str.parse::<u64>().unwrap());
+    let dists = dist_str
+        .split_whitespace()
+        .skip(1)
+        .map(|str| str.parse::<u64>().unwrap());
+
+    let part_1 = times
+        .zip(dists)
+        .map(|(duration, min_distance)| count_winning_options(&duration, &min_distance))
+        .product::<u64>();
+
+    let combined_time = time_str
+        .split_whitespace()
+        .skip(1)
+        .collect::<Vec<&str>>()
+        .join("")
+        .parse::<u64>()
+        .unwrap();
+
+    let combined_dist = dist_str
+        .split_whitespace()
+        .skip(1)
+        .collect::<Vec<&str>>()
+        .join("")
+        .parse::<u64>()
+        .unwrap();
+
+    let part_2 = count_winning_options(&combined_time, &combined_dist);
+
+    return (Some(part_1.into()), Some(part_2.into()));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 6);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, parse_input);
+    registry.register_generator(DAY, generate);
+}
+
+/// Up to four races, as any more would overflow the single long race.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let line = |name: &str, values: &[u64]| {
+        values.iter().fold(format!("{:<9}", name), |line, v| {
+            line + &format!(" {:>4}", v)
+        })
+    };
+    let join = |values: &[u64]| {
+        values
+            .iter()
+            .map(u64::to_string)
+            .collect::<String>()
+            .parse::<u64>()
+            .unwrap()
+    };
+
+    loop {
+        let times: Vec<u64> = (0..size.min(4)).map(|_| rng.range(7..100)).collect();
+        let distances: Vec<u64> = times.iter().map(|t| rng.range(1..t * t / 4)).collect();
+
+        // The single long race must still be winnable.
+        let (time, distance) = (join(&times), join(&distances));
+        if distance < time * time / 4 {
+            return format!(
+                "{}\n{}\n",
+                line("Time:", &times),
+                line("Distance:", &distances)
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{count_winning_options, generate, parse_input};
+
+    #[test]
+
+    fn test_count_winning_options() {
+        let races = [(7, 9, 4), (15, 40, 8), (30, 200, 9), (71530, 940200, 71503)];
+
+        for (duration, min_distance, expected) in races {
+            let result = count_winning_options(&duration, &min_distance);
+            assert_eq!(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_parse_input() {
+        let input = aoc::example!("example");
+
+        let (part_1, part_2) = parse_input(input);
+        assert_eq!(part_1, Some(288.into()));
+        assert_eq!(part_2, Some(71503.into()));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..20 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 4);
+            let (time, distance) = input.split_once("\n").unwrap();
+
+            for (time, distance) in time
+                .split_whitespace()
+                .zip(distance.split_whitespace())
+                .skip(1)
+            {
+                let (time, distance) = (time.parse().unwrap(), distance.parse().unwrap());
+                let brute_force = (0..time).filter(|t| t * (time - t) > distance).count();
+
+                assert_eq!(count_winning_options(&time, &distance), brute_force as u64);
+            }
+
+            assert!(parse_input(&input).1.is_some());
+        }
+    }
+}
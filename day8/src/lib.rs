@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+
+fn traverse(
+    start: &str,
+    map: &HashMap<&str, (&str, &str)>,
+    instructions: &str,
+    end_condition: fn(&str) -> bool,
+) -> Option<u64> {
+    let mut instructions = instructions.chars().cycle();
+    let mut location = start;
+    let mut count = Some(0);
+    while end_condition(location) {
+        count = count.map(|v| v + 1);
+        let next = &map.get(location);
+
+        location = match (next, instructions.next()) {
+            (Some(n), Some('L')) => n.0,
+            (Some(n), Some('R')) => n.1,
+            _ => {
+                count = None;
+                break;
+            }
+        };
+    }
+
+    return count;
+}
+
+fn gcd(a: u64, b: u64) -> u64 {
+    if b > 0 {
+        gcd(b, a % b)
+    } else {
+        a
+    }
+}
+
+fn lcm(a: u64, b: u64) -> u64 {
+    a * b / gcd(a, b)
+}
+
+/// Counts the steps from `AAA` to `ZZZ`, and until every `A` node is on a `Z`
+/// node at once.
+pub fn find_step_count(input: &str) -> aoc::Result {
+    let (instructions, map) = input.split_once("\n\n").unwrap();
+
+    let map = HashMap::from_iter(map.lines().map(|l| {
+        let (key, vals) = l.split_once(" = ").unwrap();
+        let val = vals[1..vals.len() - 1].split_once(", ").unwrap();
+        (key, val)
+    }));
+
+    let part_1 = traverse("AAA", &map, instructions, |l| l != "ZZZ");
+
+    let part_2 = map
+        .keys()
+        .filter(|k| k.ends_with("A"))
+        .map(|start| traverse(start, &map, instructions, |l| !l.ends_with("Z")))
+        .try_fold(1, |acc, val| val.map(|v| lcm(v, acc)));
+
+    return (part_1.map(aoc::Answer::from), part_2.map(aoc::Answer::from));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 8);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, find_step_count);
+    registry.register_generator(DAY, generate);
+}
+
+/// Up to six ghosts over about `size` nodes. As in the puzzle, each ghost
+/// walks a loop that reaches its `Z` node after a multiple of the number of
+/// instructions, and the `AAA` ghost ends on `ZZZ`.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    const LAPS: [usize; 6] = [2, 3, 5, 7, 11, 13];
+
+    let ghosts = (size / 16).clamp(1, LAPS.len());
+    let laps = &LAPS[..ghosts];
+    let length = (size / laps.iter().sum::<usize>()).clamp(1, 300);
+    let instructions: Vec<char> = (0..length).map(|_| *rng.choose(&['L', 'R'])).collect();
+
+    let letter = |i: usize| (b'A' + i as u8) as char;
+    let mut names: Vec<String> = (0..26 * 26 * 24)
+        .map(|i| {
+            format!(
+                "{}{}{}",
+                letter(i / 624),
+                letter(i / 24 % 26),
+                letter(i % 24 + 1)
+            )
+        })
+        .collect();
+    rng.shuffle(&mut names);
+
+    let mut prefixes: Vec<String> = names
+        .iter()
+        .map(|name| name[..2].to_string())
+        .filter(|prefix| prefix != "AA" && prefix != "ZZ")
+        .collect();
+    prefixes.sort();
+    prefixes.dedup();
+    rng.shuffle(&mut prefixes);
+
+    // Each ghost's nodes in walking order, from its `A` node to its `Z` node.
+    let paths: Vec<Vec<String>> = laps
+        .iter()
+        .enumerate()
+        .map(|(ghost, laps)| {
+            let (start, end) = match ghost {
+                0 => ("AAA".to_string(), "ZZZ".to_string()),
+                _ => {
+                    let prefix = prefixes.pop().unwrap();
+                    (format!("{}A", prefix), format!("{}Z", prefix))
+                }
+            };
+
+            let mut path = vec![start];
+            path.extend((1..laps * length).map(|_| names.pop().unwrap()));
+            path.push(end);
+            path
+        })
+        .collect();
+
+    let middle: Vec<String> = paths
+        .iter()
+        .flat_map(|path| path[1..path.len() - 1].iter().cloned())
+        .collect();
+
+    let mut lines = Vec::new();
+
+    for path in &paths {
+        let steps = path.len() - 1;
+
+        // The turn not taken leads somewhere else entirely, and the `Z` node
+        // leads on exactly like the `A` node, so the walk repeats.
+        let mut nodes: Vec<(String, String)> = (0..steps)
+            .map(|i| {
+                let (next, other) = (path[i + 1].clone(), rng.choose(&middle).clone());
+                match instructions[i % length] {
+                    'L' => (next, other),
+                    _ => (other, next),
+                }
+            })
+            .collect();
+        nodes.push(nodes[0].clone());
+
+        for (name, (left, right)) in path.iter().zip(nodes) {
+            lines.push(format!("{} = ({}, {})", name, left, right));
+        }
+    }
+
+    rng.shuffle(&mut lines);
+
+    return format!(
+        "{}\n\n{}\n",
+        instructions.iter().collect::<String>(),
+        lines.join("\n")
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{find_step_count, generate};
+
+    #[test]
+    fn test_find_step_count() {
+        let input = aoc::example!("example");
+
+        let (part_1, _) = find_step_count(input);
+
+        assert_eq!(part_1, Some(6.into()));
+    }
+
+    #[test]
+    fn test_find_ghost_step_count() {
+        let input = aoc::example!("example2");
+
+        let (_, part_2) = find_step_count(input);
+
+        assert_eq!(part_2, Some(6.into()));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 500);
+            let length = input.lines().next().unwrap().len() as u64;
+
+            let (part_1, part_2) = find_step_count(&input);
+            assert_eq!(part_1, Some((2 * length).into()));
+            assert!(part_2.is_some());
+        }
+    }
+}
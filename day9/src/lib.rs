@@ -0,0 +1,156 @@
+use std::collections::HashSet;
+struct Dataset {
+    data: Vec<i64>,
+}
+
+impl From<&str> for Dataset {
+    fn from(value: &str) -> Self {
+        let data = value
+            .split_whitespace()
+            .map(|v| v.parse().unwrap())
+            .collect();
+
+        return Self { data };
+    }
+}
+
+impl Dataset {
+    fn next_point(&self) -> (Option<i64>, Option<i64>) {
+        let (set, differences) = self.data.windows(2).fold(
+            (HashSet::new(), Vec::new()),
+            |acc: (HashSet<i64>, Vec<i64>), v| {
+                let (mut set, mut vec) = acc;
+                let difference = v[1] - v[0];
+                set.insert(difference);
+                vec.push(difference);
+                return (set, vec);
+            },
+        );
+
+        let all_same = set.len() == 1;
+
+        let (n_start, n_end) = match all_same {
+            true => {
+                let diff = differences.last().map(|v| v.to_owned());
+                (diff, diff)
+            }
+            false => Dataset { data: differences }.next_point(),
+        };
+
+        let start = self
+            .data
+            .first()
+            .zip(n_start)
+            .map(|(a, b)| a - b);
+
+        let end = self.data.last().zip(n_end).map(|(a, b)| a + b);
+
+        return (start, end);
+    }
+}
+
+/// Sums the extrapolated next, and previous, value of every history.
+pub fn evaluate_datapoints(input: &str) -> aoc::Result {
+    let (start, end) = input.lines().map(|l| Dataset::from(l).next_point()).fold(
+        (None, None),
+        |acc: (Option<i64>, Option<i64>), v: (Option<i64>, Option<i64>)| {
+            (
+                acc.0.map_or(v.0, |x| Some(x + v.0.unwrap())),
+                acc.1.map_or(v.1, |x| Some(x + v.1.unwrap())),
+            )
+        },
+    );
+
+    return (end.map(aoc::Answer::from), start.map(aoc::Answer::from));
+}
+
+pub const DAY: aoc::Day = aoc::Day::new(2023, 9);
+
+pub fn register(registry: &mut aoc::Registry) {
+    registry.register(DAY, evaluate_datapoints);
+    registry.register_generator(DAY, generate);
+}
+
+/// `size` histories of 21 values, each following a polynomial of degree up
+/// to six.
+pub fn generate(rng: &mut aoc::gen::Rng, size: usize) -> String {
+    let mut input = String::new();
+
+    for _ in 0..size {
+        let coefficients: Vec<i64> = (0..rng.range(1..8))
+            .map(|_| rng.range(0..9) as i64 - 4)
+            .collect();
+
+        let values: Vec<String> = (0..21)
+            .map(|x: i64| {
+                coefficients
+                    .iter()
+                    .rev()
+                    .fold(0, |acc, c| acc * x + c)
+                    .to_string()
+            })
+            .collect();
+
+        input += &values.join(" ");
+        input += "\n";
+    }
+
+    return input;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{evaluate_datapoints, generate, Dataset};
+
+    #[test]
+    fn test_next_datapoint() {
+        let examples = [
+            ("0 3 6 9 12 15", -3, 18),
+            ("1 3 6 10 15 21", 0, 28),
+            ("10 13 16 21 30 45", 5, 68),
+        ];
+
+        for (input, start, end) in examples {
+            let result = Dataset::from(input).next_point();
+
+            assert_eq!(result, (Some(start), Some(end)));
+        }
+    }
+
+    #[test]
+    fn test_sum_next_datapoints() {
+        let input = aoc::example!("example");
+
+        let result = evaluate_datapoints(input);
+
+        assert_eq!(result, (Some(114.into()), Some(2.into())));
+    }
+
+    #[test]
+    fn test_sum_negative_datapoints() {
+        let input = "\
+            -1 -3 -5 -7\n\
+            4 1 -2 -5\
+        ";
+
+        let result = evaluate_datapoints(input);
+
+        assert_eq!(result, (Some((-17).into()), Some(8.into())));
+    }
+
+    #[test]
+    fn test_generate() {
+        for seed in 0..10 {
+            let input = generate(&mut aoc::gen::Rng::new(seed), 50);
+
+            for line in input.lines() {
+                let (values, last) = line.rsplit_once(" ").unwrap();
+                let (first, rest) = values.split_once(" ").unwrap();
+                let (previous, next) = Dataset::from(rest).next_point();
+
+                assert_eq!(next, last.parse().ok());
+                assert_eq!(previous, first.parse().ok());
+            }
+        }
+    }
+}